@@ -0,0 +1,55 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+#![feature(test)]
+
+extern crate test;
+use micromap::Map;
+use test::Bencher;
+
+fn two_maps() -> (Map<u64, u64, 128>, Map<u64, u64, 128>) {
+    let mut m1: Map<u64, u64, 128> = Map::new();
+    let mut m2: Map<u64, u64, 128> = Map::new();
+    for i in 0..128 {
+        m1.insert(i, i);
+        m2.insert(127 - i, 127 - i);
+    }
+    (m1, m2)
+}
+
+#[bench]
+fn eq_by_lookup(b: &mut Bencher) {
+    let (m1, m2) = two_maps();
+    b.iter(|| {
+        for _ in 0..1000 {
+            test::black_box(test::black_box(&m1).eq(test::black_box(&m2)));
+        }
+    });
+}
+
+#[bench]
+fn eq_by_sorting(b: &mut Bencher) {
+    let (m1, m2) = two_maps();
+    b.iter(|| {
+        for _ in 0..1000 {
+            test::black_box(test::black_box(&m1).eq_sorted(test::black_box(&m2)));
+        }
+    });
+}
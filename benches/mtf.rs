@@ -0,0 +1,63 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Run it with and without the "mtf" feature to see the difference:
+// $ cargo bench --bench mtf --features mtf
+
+#![feature(test)]
+
+extern crate test;
+use micromap::Map;
+use test::Bencher;
+
+fn full() -> Map<u64, u64, 32> {
+    (0..32).map(|i| (i, i)).collect()
+}
+
+#[bench]
+fn get_index_30_slot(b: &mut Bencher) {
+    let m = full();
+    b.iter(|| {
+        for _ in 0..1000 {
+            test::black_box(m.get(test::black_box(&30)));
+        }
+    });
+}
+
+#[bench]
+fn get_mut_index_30_slot(b: &mut Bencher) {
+    let mut m = full();
+    b.iter(|| {
+        for _ in 0..1000 {
+            test::black_box(m.get_mut(test::black_box(&30)));
+        }
+    });
+}
+
+#[bench]
+fn get_mut_two_hot_keys(b: &mut Bencher) {
+    let mut m = full();
+    b.iter(|| {
+        for i in 0..1000 {
+            let k = if i % 2 == 0 { 30 } else { 31 };
+            test::black_box(m.get_mut(test::black_box(&k)));
+        }
+    });
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+#![feature(test)]
+
+extern crate test;
+use core::ops::ControlFlow;
+use micromap::Map;
+use test::Bencher;
+
+fn full() -> Map<u64, u64, 64> {
+    (0..64).map(|i| (i, i)).collect()
+}
+
+fn compacting(b: &mut Bencher, keep: u64) {
+    b.iter(|| {
+        let mut m = full();
+        m.retain(|k, _| k % 10 < keep);
+        test::black_box(m.len())
+    });
+}
+
+fn swapping(b: &mut Bencher, keep: u64) {
+    b.iter(|| {
+        let mut m = full();
+        m.try_retain(|k, _| ControlFlow::<(), bool>::Continue(k % 10 < keep));
+        test::black_box(m.len())
+    });
+}
+
+#[bench]
+fn retain_few_removed(b: &mut Bencher) {
+    compacting(b, 9);
+}
+
+#[bench]
+fn try_retain_few_removed(b: &mut Bencher) {
+    swapping(b, 9);
+}
+
+#[bench]
+fn retain_half_removed(b: &mut Bencher) {
+    compacting(b, 5);
+}
+
+#[bench]
+fn try_retain_half_removed(b: &mut Bencher) {
+    swapping(b, 5);
+}
+
+#[bench]
+fn retain_most_removed(b: &mut Bencher) {
+    compacting(b, 1);
+}
+
+#[bench]
+fn try_retain_most_removed(b: &mut Bencher) {
+    swapping(b, 1);
+}
@@ -0,0 +1,62 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, K: PartialEq + Arbitrary<'a>, V: Arbitrary<'a>, const N: usize> Arbitrary<'a>
+    for Map<K, V, N>
+{
+    /// Make a map of pairs taken from the input, until it ends or
+    /// a new key doesn't fit. Values of duplicate keys are replaced.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut m = Self::new();
+        for p in u.arbitrary_iter::<(K, V)>()? {
+            let (k, v) = p?;
+            if m.insert_checked(k, v).is_err() {
+                break;
+            }
+        }
+        Ok(m)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn makes_map_from_bytes() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&bytes);
+        let m = Map::<u8, u8, 4>::arbitrary(&mut u).unwrap();
+        assert!(m.len() <= 4);
+        for (i, k) in m.keys().enumerate() {
+            assert!(!m.keys().skip(i + 1).any(|x| x == k));
+        }
+    }
+
+    #[test]
+    fn makes_map_from_empty_input() {
+        let mut u = Unstructured::new(&[]);
+        assert!(Map::<u8, u8, 4>::arbitrary(&mut u).unwrap().is_empty());
+    }
+}
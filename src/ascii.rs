@@ -0,0 +1,74 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use core::borrow::Borrow;
+
+impl<K: PartialEq + Borrow<str>, V, const N: usize> Map<K, V, N> {
+    /// Get a reference to a single value, comparing keys case-insensitively.
+    ///
+    /// Only ASCII letters are compared ignoring their case, all other
+    /// bytes must match exactly, as in [`str::eq_ignore_ascii_case`].
+    ///
+    /// ```
+    /// let mut m: micromap::Map<&str, i32, 4> = micromap::Map::new();
+    /// m.insert("Timeout", 42);
+    /// assert_eq!(Some(&42), m.get_ignore_ascii_case("TIMEOUT"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_ignore_ascii_case(&self, k: &str) -> Option<&V> {
+        self.iter()
+            .find(|(key, _)| (*key).borrow().eq_ignore_ascii_case(k))
+            .map(|p| p.1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn gets_ignoring_ascii_case() {
+        let mut m: Map<String, i32, 4> = Map::new();
+        m.insert("key".to_string(), 42);
+        assert_eq!(Some(&42), m.get_ignore_ascii_case("Key"));
+        assert_eq!(Some(&42), m.get_ignore_ascii_case("KEY"));
+        assert_eq!(None, m.get_ignore_ascii_case("keys"));
+    }
+
+    #[test]
+    fn gets_from_map_of_str() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("Port", 80);
+        assert_eq!(Some(&80), m.get_ignore_ascii_case("port"));
+    }
+
+    #[test]
+    fn compares_non_ascii_literally() {
+        let mut m: Map<&str, i32, 4> = Map::new();
+        m.insert("straße", 1);
+        m.insert("ÿ", 2);
+        assert_eq!(Some(&1), m.get_ignore_ascii_case("STRAße"));
+        assert_eq!(None, m.get_ignore_ascii_case("STRASSE"));
+        assert_eq!(None, m.get_ignore_ascii_case("Ÿ"));
+    }
+}
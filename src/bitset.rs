@@ -0,0 +1,75 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+
+impl<K: PartialEq + Copy + Into<u8>, V, const N: usize> Map<K, V, N> {
+    /// Get a bitmask of the keys, where the i-th bit is set if the key
+    /// equal to `i` is in the map.
+    ///
+    /// Keys equal to 128 and above don't fit and are ignored. With the mask
+    /// of two maps, subset and membership checks are single bit operations.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, i32, 10> = micromap::Map::new();
+    /// m.insert(0, 42);
+    /// m.insert(2, 16);
+    /// assert_eq!(0b101, m.key_bitset());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn key_bitset(&self) -> u128 {
+        self.keys()
+            .map(|k| (*k).into())
+            .filter(|k| *k < 128)
+            .fold(0, |mask, k| mask | (1 << k))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn makes_bitset_of_keys() {
+        let mut m: Map<u8, i32, 10> = Map::new();
+        for k in [1, 3, 7] {
+            m.insert(k, 0);
+        }
+        let bits = m.key_bitset();
+        assert_eq!(0b1000_1010, bits);
+        for k in 0..128 {
+            assert_eq!(m.contains_key(&k), bits & (1 << k) != 0);
+        }
+    }
+
+    #[test]
+    fn ignores_large_keys_in_bitset() {
+        let mut m: Map<u8, i32, 10> = Map::new();
+        m.insert(127, 0);
+        m.insert(128, 0);
+        m.insert(255, 0);
+        assert_eq!(1 << 127, m.key_bitset());
+        assert_eq!(0, Map::<bool, i32, 2>::new().key_bitset());
+    }
+}
@@ -25,8 +25,15 @@ impl<K: Clone + PartialEq, V: Clone, const N: usize> Clone for Map<K, V, N> {
         let mut m: Self = Self::new();
         for i in 0..self.len {
             m.item_write(i, self.item_ref(i).clone());
+            // if the next clone() panics, only the pairs
+            // cloned so far will be dropped together with the map
+            m.len += 1;
+        }
+        #[cfg(feature = "insertion_order")]
+        {
+            m.order = self.order;
+            m.next = self.next;
         }
-        m.len = self.len;
         m
     }
 }
@@ -44,8 +51,40 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::redundant_clone)]
     fn empty_map_can_be_cloned() {
         let m: Map<u8, u8, 0> = Map::new();
         assert!(m.clone().is_empty());
     }
+
+    #[test]
+    fn drops_cloned_pairs_when_clone_panics() {
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+        #[derive(PartialEq)]
+        struct Key(u8, Rc<Cell<usize>>);
+        impl Clone for Key {
+            fn clone(&self) -> Self {
+                assert!(self.0 != 2, "Can't clone the third key");
+                Self(self.0, Rc::clone(&self.1))
+            }
+        }
+        impl Drop for Key {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+            }
+        }
+        let drops = Rc::new(Cell::new(0));
+        let mut m: Map<Key, u8, 8> = Map::new();
+        for i in 0..4 {
+            m.insert(Key(i, Rc::clone(&drops)), i);
+        }
+        let r = catch_unwind(AssertUnwindSafe(|| m.clone()));
+        assert!(r.is_err());
+        assert_eq!(2, drops.get());
+        assert_eq!(4, m.len());
+        drop(m);
+        assert_eq!(6, drops.get());
+    }
 }
@@ -24,7 +24,6 @@ use core::mem::MaybeUninit;
 impl<K: PartialEq, V, const N: usize> Default for Map<K, V, N> {
     /// Make a default empty [`Map`].
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self::new()
     }
@@ -37,17 +36,77 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     /// this is how you make a map of four key-values pairs:
     #[inline]
     #[must_use]
-    #[allow(clippy::uninit_assumed_init)]
     pub const fn new() -> Self {
+        Self::empty()
+    }
+
+    /// Make an empty map, also in `const` context.
+    ///
+    /// This is the same as [`Map::new`], but the name reads better
+    /// where the map is declared as a constant:
+    ///
+    /// ```
+    /// const EMPTY: micromap::Map<u8, i32, 4> = micromap::Map::empty();
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::uninit_assumed_init)]
+    pub const fn empty() -> Self {
         unsafe {
             Self {
                 len: 0,
                 pairs: MaybeUninit::<[MaybeUninit<(K, V)>; N]>::uninit().assume_init(),
+                #[cfg(feature = "insertion_order")]
+                order: [0; N],
+                #[cfg(feature = "insertion_order")]
+                next: 0,
             }
         }
     }
 }
 
+impl<K: PartialEq + Copy, V: Copy, const N: usize> Map<K, V, N> {
+    /// Make a map from a slice of pairs with distinct keys, copying them
+    /// all at once, without comparing keys.
+    ///
+    /// In the "debug" mode the keys are still checked to be distinct.
+    ///
+    /// # Safety
+    ///
+    /// All keys in the slice must be distinct. Otherwise, the map will
+    /// contain duplicate keys and its behaviour is not specified.
+    ///
+    /// # Panics
+    ///
+    /// If there are more pairs in the slice than `N`, or, in the "debug" mode,
+    /// if some keys are equal.
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_distinct_slice_unchecked(pairs: &[(K, V)]) -> Self {
+        assert!(pairs.len() <= N, "There are more pairs than {N}");
+        debug_assert!(
+            (1..pairs.len()).all(|i| pairs[..i].iter().all(|p| p.0 != pairs[i].0)),
+            "The keys are not distinct"
+        );
+        let mut m = Self::new();
+        // SAFETY: the slice fits into the array, they don't overlap,
+        // and pairs are `Copy`, so copying them bitwise is fine
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                pairs.as_ptr(),
+                m.pairs.as_mut_ptr().cast::<(K, V)>(),
+                pairs.len(),
+            );
+        }
+        for i in 0..pairs.len() {
+            m.order_new(i);
+        }
+        m.len = pairs.len();
+        m
+    }
+}
+
 impl<K: PartialEq, V, const N: usize> Drop for Map<K, V, N> {
     fn drop(&mut self) {
         for i in 0..self.len {
@@ -73,6 +132,13 @@ mod test {
         assert_eq!(0, m.len());
     }
 
+    #[test]
+    fn makes_empty_map_in_const_context() {
+        const M: Map<u8, u8, 8> = Map::empty();
+        assert_eq!(0, M.len());
+        assert_eq!(8, M.capacity());
+    }
+
     #[test]
     fn drops_correctly() {
         let _m: Map<Vec<u8>, u8, 8> = Map::new();
@@ -97,4 +163,29 @@ mod test {
         drop(m);
         assert_eq!(Rc::strong_count(&v), 1);
     }
+
+    #[test]
+    fn makes_from_distinct_slice() {
+        let m: Map<u8, i32, 4> =
+            unsafe { Map::from_distinct_slice_unchecked(&[(1, 10), (2, 20), (3, 30)]) };
+        assert_eq!(3, m.len());
+        assert_eq!(20, m[&2]);
+        let e: Map<u8, i32, 4> = unsafe { Map::from_distinct_slice_unchecked(&[]) };
+        assert!(e.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "The keys are not distinct")]
+    #[cfg(debug_assertions)]
+    fn makes_from_slice_with_duplicates() {
+        let _m: Map<u8, i32, 4> =
+            unsafe { Map::from_distinct_slice_unchecked(&[(1, 10), (2, 20), (1, 30)]) };
+    }
+
+    #[test]
+    #[should_panic(expected = "There are more pairs than 2")]
+    fn makes_from_too_long_slice() {
+        let _m: Map<u8, i32, 2> =
+            unsafe { Map::from_distinct_slice_unchecked(&[(1, 10), (2, 20), (3, 30)]) };
+    }
 }
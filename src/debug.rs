@@ -27,12 +27,55 @@ impl<K: PartialEq + Debug, V: Debug, const N: usize> Debug for Map<K, V, N> {
     }
 }
 
+/// A [`Debug`] view of the [`Map`], which prints its pairs sorted by keys.
+struct SortedDebug<'a, K: PartialEq, V, const N: usize> {
+    map: &'a Map<K, V, N>,
+}
+
+impl<K: Ord + Debug, V: Debug, const N: usize> Debug for SortedDebug<'_, K, V, N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let indices = self.map.sorted_indices();
+        f.debug_map()
+            .entries(indices[..self.map.len()].iter().map(|i| {
+                let p = self.map.item_ref(*i);
+                (&p.0, &p.1)
+            }))
+            .finish()
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Make a [`Debug`] view, which prints pairs sorted by keys.
+    ///
+    /// The order of pairs in the [`Map`] depends on the history of
+    /// insertions and removals, which is why the output of `{:?}` is not
+    /// stable. This view prints the same string for equal maps, which is
+    /// handy for snapshot testing:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, &str, 4> = micromap::Map::new();
+    /// m.insert(2, "two");
+    /// m.insert(1, "one");
+    /// assert_eq!(r#"{1: "one", 2: "two"}"#, format!("{:?}", m.debug_sorted()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn debug_sorted(&self) -> impl Debug + '_
+    where
+        K: Ord + Debug,
+        V: Debug,
+    {
+        SortedDebug { map: self }
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
 
     #[test]
+    #[allow(clippy::uninlined_format_args)]
     fn debugs_map() {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("one".to_string(), 42);
@@ -41,6 +84,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::uninlined_format_args)]
     fn debug_alternate_map() {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("one".to_string(), 42);
@@ -53,4 +97,31 @@ mod test {
             format!("{:#?}", m)
         );
     }
+
+    #[test]
+    fn debugs_sorted_map() {
+        let mut m1: Map<String, i32, 10> = Map::new();
+        m1.insert("two".to_string(), 16);
+        m1.insert("one".to_string(), 42);
+        m1.insert("three".to_string(), 7);
+        let mut m2: Map<String, i32, 10> = Map::new();
+        m2.insert("three".to_string(), 7);
+        m2.insert("one".to_string(), 42);
+        m2.insert("two".to_string(), 16);
+        assert_ne!(format!("{m1:?}"), format!("{m2:?}"));
+        assert_eq!(
+            format!("{:?}", m1.debug_sorted()),
+            format!("{:?}", m2.debug_sorted())
+        );
+        assert_eq!(
+            r#"{"one": 42, "three": 7, "two": 16}"#,
+            format!("{:?}", m1.debug_sorted())
+        );
+    }
+
+    #[test]
+    fn debugs_sorted_empty_map() {
+        let m: Map<u8, u8, 0> = Map::new();
+        assert_eq!("{}", format!("{:?}", m.debug_sorted()));
+    }
 }
@@ -22,6 +22,8 @@ use crate::Map;
 use core::fmt::{self, Display, Formatter, Write};
 
 impl<K: PartialEq + Display, V: Display, const N: usize> Display for Map<K, V, N> {
+    /// Print the pairs as `{k1: v1, k2: v2}`, in the order they are stored
+    /// in the map. Only `core::fmt` is used, so it works without `std`.
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let mut first = true;
         f.write_char('{')?;
@@ -46,10 +48,17 @@ mod test {
     use super::*;
 
     #[test]
+    #[allow(clippy::needless_raw_string_hashes, clippy::uninlined_format_args)]
     fn displays_map() {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("one".to_string(), 42);
         m.insert("two".to_string(), 16);
         assert_eq!(r#"{one: 42, two: 16}"#, format!("{}", m));
     }
+
+    #[test]
+    fn displays_empty_map() {
+        let m: Map<u8, i32, 10> = Map::new();
+        assert_eq!("{}", format!("{m}"));
+    }
 }
@@ -18,10 +18,26 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::Drain;
+use crate::{Drain, Map};
+use core::fmt::{self, Debug, Formatter};
 use core::iter::FusedIterator;
 
-impl<'a, K, V> Drop for Drain<'a, K, V> {
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Clears the map, returning all pairs transformed by the function.
+    ///
+    /// It is the same as `drain().map(f)`: if the returned iterator is dropped
+    /// before being fully consumed, it drops the remaining pairs, without
+    /// calling the function on them.
+    #[inline]
+    pub fn drain_map<'a, W, F: FnMut(K, V) -> W + 'a>(
+        &'a mut self,
+        mut f: F,
+    ) -> impl Iterator<Item = W> + 'a {
+        self.drain().map(move |(k, v)| f(k, v))
+    }
+}
+
+impl<K, V> Drop for Drain<'_, K, V> {
     fn drop(&mut self) {
         for pair in &mut self.iter {
             unsafe { pair.assume_init_drop() };
@@ -29,11 +45,10 @@ impl<'a, K, V> Drop for Drain<'a, K, V> {
     }
 }
 
-impl<'a, K: PartialEq, V> Iterator for Drain<'a, K, V> {
+impl<K: PartialEq, V> Iterator for Drain<'_, K, V> {
     type Item = (K, V);
 
     #[inline]
-    #[must_use]
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|p| unsafe { p.assume_init_read() })
     }
@@ -44,11 +59,78 @@ impl<'a, K: PartialEq, V> Iterator for Drain<'a, K, V> {
     }
 }
 
-impl<'a, K: PartialEq, V> ExactSizeIterator for Drain<'a, K, V> {
+impl<K: PartialEq, V> DoubleEndedIterator for Drain<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|p| unsafe { p.assume_init_read() })
+    }
+}
+
+impl<K: PartialEq, V> ExactSizeIterator for Drain<'_, K, V> {
     #[inline]
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<'a, K: PartialEq, V> FusedIterator for Drain<'a, K, V> {}
+impl<K: PartialEq, V> FusedIterator for Drain<'_, K, V> {}
+
+impl<K: Debug, V: Debug> Debug for Drain<'_, K, V> {
+    /// Print the pairs not drained yet.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.iter
+                    .as_slice()
+                    .iter()
+                    .map(|p| unsafe { p.assume_init_ref() }),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn drains_and_maps() {
+        let mut m: Map<u8, i32, 10> = Map::new();
+        m.insert(1, 42);
+        m.insert(2, 16);
+        let total: i32 = m.drain_map(|k, v| i32::from(k) * v).sum();
+        assert_eq!(74, total);
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn drops_remaining_pairs_of_drain_map() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<u8, Rc<()>, 10> = Map::new();
+        for k in 0..5 {
+            m.insert(k, Rc::clone(&v));
+        }
+        let first = m.drain_map(|k, v| (k, v)).next();
+        assert!(m.is_empty());
+        assert_eq!(2, Rc::strong_count(&v));
+        drop(first);
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn drains_from_both_ends() {
+        let mut m: Map<u8, i32, 10> = Map::new();
+        m.insert(1, 10);
+        m.insert(2, 20);
+        m.insert(3, 30);
+        let mut d = m.drain();
+        assert_eq!(Some((3, 30)), d.next_back());
+        assert_eq!("[(1, 10), (2, 20)]", format!("{d:?}"));
+        assert_eq!(Some((1, 10)), d.next());
+        assert_eq!(1, d.len());
+    }
+}
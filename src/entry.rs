@@ -18,9 +18,50 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::{Entry, OccupiedEntry, VacantEntry};
+use crate::{Entry, Map, OccupiedEntry, VacantEntry};
 use core::mem;
 
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Get the entry of the key, only if the key is in the map or
+    /// there is space for it.
+    ///
+    /// # Errors
+    ///
+    /// If the key is absent and the map is full, the key is returned back,
+    /// so that [`VacantEntry::insert`] never panics.
+    #[inline]
+    pub fn try_entry(&mut self, k: K) -> Result<Entry<'_, K, V, N>, K> {
+        match self.entry(k) {
+            Entry::Vacant(e) if e.table.len == N => Err(e.key),
+            e => Ok(e),
+        }
+    }
+
+    /// Get the entry of the pair in the first slot of the internal array.
+    #[inline]
+    pub const fn first_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, N>> {
+        if self.len == 0 {
+            return None;
+        }
+        Some(OccupiedEntry {
+            index: 0,
+            table: self,
+        })
+    }
+
+    /// Get the entry of the pair in the last slot of the internal array.
+    #[inline]
+    pub const fn last_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, N>> {
+        if self.len == 0 {
+            return None;
+        }
+        Some(OccupiedEntry {
+            index: self.len - 1,
+            table: self,
+        })
+    }
+}
+
 impl<'a, K: PartialEq, V, const N: usize> Entry<'a, K, V, N> {
     pub fn or_insert(self, default: V) -> &'a mut V {
         match self {
@@ -46,6 +87,7 @@ impl<'a, K: PartialEq, V, const N: usize> Entry<'a, K, V, N> {
         }
     }
 
+    #[allow(clippy::missing_const_for_fn)]
     pub fn key(&self) -> &K {
         match self {
             Entry::Occupied(entry) => entry.key(),
@@ -53,6 +95,41 @@ impl<'a, K: PartialEq, V, const N: usize> Entry<'a, K, V, N> {
         }
     }
 
+    /// Is the key in the map?
+    #[must_use]
+    pub const fn is_occupied(&self) -> bool {
+        matches!(self, Entry::Occupied(_))
+    }
+
+    /// Is the key absent in the map?
+    #[must_use]
+    pub const fn is_vacant(&self) -> bool {
+        matches!(self, Entry::Vacant(_))
+    }
+
+    /// Sets the value of the entry and returns an [`OccupiedEntry`].
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, N> {
+        match self {
+            Entry::Occupied(mut entry) => {
+                entry.insert(value);
+                entry
+            }
+            Entry::Vacant(entry) => entry.insert_entry(value),
+        }
+    }
+
+    /// Replace the value of an occupied entry with the result of the closure,
+    /// or remove the entry if the closure returns `None`.
+    ///
+    /// A vacant entry is returned untouched.
+    #[must_use]
+    pub fn and_replace_entry_with<F: FnOnce(&K, V) -> Option<V>>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(entry) => entry.replace_entry_with(f),
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+
     #[must_use]
     pub fn and_modify<F>(self, f: F) -> Self
     where
@@ -79,33 +156,88 @@ impl<'a, K: PartialEq, V: Default, const N: usize> Entry<'a, K, V, N> {
 
 impl<'a, K: PartialEq, V, const N: usize> OccupiedEntry<'a, K, V, N> {
     #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
     pub fn key(&self) -> &K {
         &self.table.item_ref(self.index).0
     }
 
+    /// The index of the pair in the internal array of the map.
+    ///
+    /// The index is valid only until the next mutating operation
+    /// on the map, since removals move pairs around.
     #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
     pub fn remove_entry(self) -> (K, V) {
         self.table.remove_index_read(self.index)
     }
 
     #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
     pub fn get(&self) -> &V {
         &self.table.item_ref(self.index).1
     }
 
+    #[allow(clippy::missing_const_for_fn)]
     pub fn get_mut(&mut self) -> &mut V {
         self.table.item_mut(self.index)
     }
 
     #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
     pub fn into_mut(self) -> &'a mut V {
         self.table.item_mut(self.index)
     }
 
+    #[allow(clippy::missing_const_for_fn)]
     pub fn insert(&mut self, value: V) -> V {
         mem::replace(self.get_mut(), value)
     }
 
+    /// Replace the value with the result of the closure, or remove the pair
+    /// if the closure returns `None`.
+    ///
+    /// If the pair is removed, the entry becomes [`Entry::Vacant`],
+    /// holding the key, so that a new value may be inserted back.
+    /// If the closure panics, the pair is gone, but the map stays consistent.
+    #[must_use]
+    pub fn replace_entry_with<F: FnOnce(&K, V) -> Option<V>>(self, f: F) -> Entry<'a, K, V, N> {
+        let last = self.table.len - 1;
+        self.table.swap_slots(self.index, last);
+        self.table.len = last;
+        let (k, v) = self.table.item_read(last);
+        match f(&k, v) {
+            Some(v) => {
+                self.table.item_write(last, (k, v));
+                self.table.len += 1;
+                self.table.swap_slots(self.index, last);
+                Entry::Occupied(self)
+            }
+            None => Entry::Vacant(VacantEntry {
+                key: k,
+                table: self.table,
+            }),
+        }
+    }
+
+    /// Replace the stored key with the given one, which must be equal to it,
+    /// and return the old key.
+    ///
+    /// It's useful when keys are equal, but not identical.
+    pub fn replace_key(&mut self, key: K) -> K {
+        debug_assert!(
+            key == *self.key(),
+            "The new key is not equal to the stored one"
+        );
+        let (old, v) = self.table.item_read(self.index);
+        self.table.item_write(self.index, (key, v));
+        old
+    }
+
     #[must_use]
     pub fn remove(self) -> V {
         self.table.remove_index_read(self.index).1
@@ -125,4 +257,193 @@ impl<'a, K: PartialEq, V, const N: usize> VacantEntry<'a, K, V, N> {
         let (index, _) = self.table.insert_i(self.key, value);
         self.table.item_mut(index)
     }
+
+    /// Sets the value of the entry and returns an [`OccupiedEntry`].
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, N> {
+        let (index, _) = self.table.insert_i(self.key, value);
+        OccupiedEntry {
+            index,
+            table: self.table,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{Entry, Map};
+
+    #[test]
+    fn insert_entry_index() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 1);
+        m.insert("two".to_string(), 2);
+        for k in ["three", "one"] {
+            let index = m.entry(k.to_string()).insert_entry(42).index();
+            assert_eq!(m.iter().position(|(key, _)| key == k), Some(index));
+        }
+        assert_eq!(42, m["one"]);
+        assert_eq!(42, m["three"]);
+    }
+
+    #[test]
+    fn try_entry_of_occupied_key_in_full_map() {
+        let mut m: Map<u8, i32, 2> = Map::new();
+        m.insert(1, 42);
+        m.insert(2, 16);
+        assert!(matches!(m.try_entry(1), Ok(Entry::Occupied(_))));
+        *m.try_entry(2).unwrap().or_insert(0) += 1;
+        assert_eq!(17, m[&2]);
+    }
+
+    #[test]
+    fn try_entry_of_absent_key_in_full_map() {
+        let mut m: Map<u8, i32, 2> = Map::new();
+        m.insert(1, 42);
+        m.insert(2, 16);
+        assert!(matches!(m.try_entry(3), Err(3)));
+        assert!(matches!(Map::<u8, i32, 0>::new().try_entry(1), Err(1)));
+    }
+
+    #[test]
+    fn try_entry_of_absent_key_with_room() {
+        let mut m: Map<u8, i32, 2> = Map::new();
+        m.insert(1, 42);
+        assert!(matches!(m.try_entry(3), Ok(Entry::Vacant(_))));
+        m.try_entry(3).unwrap().or_insert(8);
+        assert_eq!(8, m[&3]);
+    }
+
+    #[derive(Debug)]
+    struct Book {
+        isbn: u32,
+        title: &'static str,
+    }
+
+    impl PartialEq for Book {
+        fn eq(&self, other: &Self) -> bool {
+            self.isbn == other.isbn
+        }
+    }
+
+    #[test]
+    fn replaces_key_of_occupied_entry() {
+        let mut m: Map<Book, i32, 4> = Map::new();
+        m.insert(
+            Book {
+                isbn: 1,
+                title: "draft",
+            },
+            42,
+        );
+        let Entry::Occupied(mut e) = m.entry(Book { isbn: 1, title: "" }) else {
+            panic!("The entry must be occupied");
+        };
+        let old = e.replace_key(Book {
+            isbn: 1,
+            title: "final",
+        });
+        assert_eq!("draft", old.title);
+        let (k, v) = m.get_key_value(&Book { isbn: 1, title: "" }).unwrap();
+        assert_eq!("final", k.title);
+        assert_eq!(42, *v);
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "The new key is not equal to the stored one")]
+    #[cfg(debug_assertions)]
+    fn replaces_key_with_different_one() {
+        let mut m: Map<u8, i32, 4> = Map::new();
+        m.insert(1, 42);
+        if let Entry::Occupied(mut e) = m.entry(1) {
+            e.replace_key(2);
+        }
+    }
+
+    #[test]
+    fn replaces_entry_with_new_value() {
+        let mut m: Map<u8, i32, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.insert(3, 3);
+        let e = m
+            .entry(2)
+            .and_replace_entry_with(|k, v| Some(v + i32::from(*k) * 10));
+        assert!(matches!(e, Entry::Occupied(_)));
+        assert_eq!(22, *e.or_insert(0));
+        assert_eq!(3, m.len());
+        assert_eq!(vec![1, 2, 3], m.keys().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn replaces_entry_with_nothing() {
+        let mut m: Map<u8, i32, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.insert(3, 3);
+        let e = m.entry(1).and_replace_entry_with(|_, _| None);
+        assert!(matches!(e, Entry::Vacant(_)));
+        assert_eq!(&1, e.key());
+        assert_eq!(2, m.len());
+        assert!(m.get(&1).is_none());
+        assert_eq!(3, m[&3]);
+        m.entry(1)
+            .and_replace_entry_with(|_, _| Some(42))
+            .or_insert(8);
+        assert_eq!(8, m[&1]);
+    }
+
+    #[test]
+    fn replaces_entry_with_panic() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<u8, Rc<()>, 4> = Map::new();
+        m.insert(1, Rc::clone(&v));
+        m.insert(2, Rc::clone(&v));
+        let r = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = m.entry(1).and_replace_entry_with(|_, _| panic!("boom"));
+        }));
+        assert!(r.is_err());
+        assert_eq!(1, m.len());
+        assert!(m.contains_key(&2));
+        drop(m);
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn checks_occupancy_of_entry() {
+        let mut m: Map<u8, i32, 4> = Map::new();
+        assert!(m.entry(1).is_vacant());
+        assert!(!m.entry(1).is_occupied());
+        m.insert(1, 42);
+        let e = m.entry(1);
+        assert!(e.is_occupied());
+        assert!(!e.is_vacant());
+        assert_eq!(42, *e.or_insert(0));
+    }
+
+    #[test]
+    fn removes_first_entry() {
+        let mut m: Map<u8, i32, 4> = Map::new();
+        m.insert(1, 10);
+        m.insert(2, 20);
+        m.insert(3, 30);
+        assert_eq!(10, m.first_entry().unwrap().remove());
+        assert_eq!(vec![3, 2], m.keys().copied().collect::<Vec<_>>());
+        assert_eq!(&3, m.first_entry().unwrap().key());
+    }
+
+    #[test]
+    fn modifies_last_entry() {
+        let mut m: Map<u8, i32, 4> = Map::new();
+        assert!(m.first_entry().is_none());
+        assert!(m.last_entry().is_none());
+        m.insert(1, 10);
+        m.insert(2, 20);
+        *m.last_entry().unwrap().get_mut() += 2;
+        assert_eq!(22, m[&2]);
+        assert_eq!((2, 22), m.last_entry().unwrap().remove_entry());
+        assert_eq!(1, m.len());
+    }
 }
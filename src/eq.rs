@@ -20,8 +20,10 @@
 
 use crate::Map;
 
-impl<K: PartialEq, V: PartialEq, const N: usize> PartialEq for Map<K, V, N> {
-    /// Two maps can be compared.
+impl<K: PartialEq, V: PartialEq, const N: usize, const M: usize> PartialEq<Map<K, V, M>>
+    for Map<K, V, N>
+{
+    /// Two maps can be compared, even if their capacities are different.
     ///
     /// For example:
     ///
@@ -41,13 +43,66 @@ impl<K: PartialEq, V: PartialEq, const N: usize> PartialEq for Map<K, V, N> {
     /// assert_eq!(m1, m2);
     /// ```
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        return self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v));
+    fn eq(&self, other: &Map<K, V, M>) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
     }
 }
 
 impl<K: Eq, V: Eq, const N: usize> Eq for Map<K, V, N> {}
 
+impl<K: PartialEq, V: PartialEq, const N: usize> Map<K, V, N> {
+    /// Does the map contain exactly the pairs of the slice, in any order?
+    ///
+    /// Keys in the slice are expected to be distinct.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, i32, 10> = micromap::Map::new();
+    /// m.insert(1, 42);
+    /// m.insert(2, 16);
+    /// assert!(m.eq_slice(&[(2, 16), (1, 42)]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn eq_slice(&self, slice: &[(K, V)]) -> bool {
+        self.len == slice.len() && slice.iter().all(|(k, v)| self.get(k) == Some(v))
+    }
+}
+
+impl<K: Ord, V: PartialEq, const N: usize> Map<K, V, N> {
+    /// Compare two maps by sorting their keys first, in O(n log n).
+    ///
+    /// The result is always the same as of [`PartialEq::eq`], which is O(n²),
+    /// but for large maps with ordered keys this one is faster. Stable Rust
+    /// doesn't have specialization, that's why it's a separate method.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m1: micromap::Map<u8, i32, 10> = micromap::Map::new();
+    /// let mut m2: micromap::Map<u8, i32, 10> = micromap::Map::new();
+    /// m1.insert(1, 42);
+    /// m1.insert(2, 16);
+    /// m2.insert(2, 16);
+    /// m2.insert(1, 42);
+    /// assert!(m1.eq_sorted(&m2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn eq_sorted(&self, other: &Self) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+        let mine = self.sorted_indices();
+        let theirs = other.sorted_indices();
+        mine[..self.len]
+            .iter()
+            .zip(&theirs[..other.len])
+            .all(|(a, b)| self.item_ref(*a) == other.item_ref(*b))
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -61,4 +116,90 @@ mod test {
         m2.insert("first".to_string(), 42);
         assert!(m1.eq(&m2));
     }
+
+    #[test]
+    fn compares_two_equal_maps_sorted() {
+        let mut m1: Map<String, i32, 10> = Map::new();
+        m1.insert("first".to_string(), 42);
+        m1.insert("second".to_string(), 16);
+        let mut m2: Map<String, i32, 10> = Map::new();
+        m2.insert("second".to_string(), 16);
+        m2.insert("first".to_string(), 42);
+        assert!(m1.eq_sorted(&m2));
+        assert!(m1.eq(&m2));
+    }
+
+    #[test]
+    fn compares_two_different_maps_sorted() {
+        let mut m1: Map<u8, i32, 10> = Map::new();
+        m1.insert(1, 42);
+        m1.insert(2, 16);
+        let mut m2: Map<u8, i32, 10> = Map::new();
+        m2.insert(1, 42);
+        assert!(!m1.eq_sorted(&m2));
+        m2.insert(3, 16);
+        assert!(!m1.eq_sorted(&m2));
+        m2.remove(&3);
+        m2.insert(2, 8);
+        assert!(!m1.eq_sorted(&m2));
+    }
+
+    #[test]
+    fn compares_two_empty_maps_sorted() {
+        let m1: Map<u8, i32, 10> = Map::new();
+        let m2: Map<u8, i32, 10> = Map::new();
+        assert!(m1.eq_sorted(&m2));
+    }
+
+    #[test]
+    fn compares_maps_of_different_length_without_scanning() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+        struct Key(u8, Rc<Cell<usize>>);
+        impl PartialEq for Key {
+            fn eq(&self, other: &Self) -> bool {
+                self.1.set(self.1.get() + 1);
+                self.0 == other.0
+            }
+        }
+        let calls = Rc::new(Cell::new(0));
+        let mut m1: Map<Key, i32, 10> = Map::new();
+        let mut m2: Map<Key, i32, 10> = Map::new();
+        for i in 0..5 {
+            m1.insert(Key(i, Rc::clone(&calls)), 42);
+            m2.insert(Key(i, Rc::clone(&calls)), 42);
+        }
+        m2.insert(Key(5, Rc::clone(&calls)), 42);
+        calls.set(0);
+        assert!(m1.ne(&m2));
+        assert!(m2.ne(&m1));
+        assert_eq!(0, calls.get());
+    }
+
+    #[test]
+    fn compares_with_slice() {
+        let mut m: Map<u8, i32, 10> = Map::new();
+        m.insert(1, 10);
+        m.insert(2, 20);
+        m.insert(3, 30);
+        assert!(m.eq_slice(&[(3, 30), (1, 10), (2, 20)]));
+        assert!(!m.eq_slice(&[(1, 10), (2, 20)]));
+        assert!(!m.eq_slice(&[(1, 10), (2, 20), (3, 30), (4, 40)]));
+        assert!(!m.eq_slice(&[(1, 10), (2, 20), (3, 31)]));
+        assert!(Map::<u8, i32, 1>::new().eq_slice(&[]));
+    }
+
+    #[test]
+    fn compares_maps_of_different_capacities() {
+        let mut m1: Map<u8, i32, 5> = Map::new();
+        let mut m2: Map<u8, i32, 10> = Map::new();
+        for k in 0..5 {
+            m1.insert(k, i32::from(k));
+            m2.insert(4 - k, i32::from(4 - k));
+        }
+        assert_eq!(m1, m2);
+        assert_eq!(m2, m1);
+        m2.insert(1, 42);
+        assert_ne!(m1, m2);
+    }
 }
@@ -0,0 +1,97 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{CapacityError, OccupiedError, TryInsertError};
+use core::fmt::{self, Debug, Display, Formatter};
+
+impl<K: PartialEq + Debug, V: Debug, const N: usize> Debug for OccupiedError<'_, K, V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OccupiedError")
+            .field("key", self.entry.key())
+            .field("old_value", self.entry.get())
+            .field("new_value", &self.value)
+            .finish()
+    }
+}
+
+impl<K: PartialEq + Debug, V: Debug, const N: usize> Display for OccupiedError<'_, K, V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to insert {:?}, key {:?} already exists with value {:?}",
+            self.value,
+            self.entry.key(),
+            self.entry.get(),
+        )
+    }
+}
+
+impl<K, V> Display for CapacityError<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("No more keys available in the map")
+    }
+}
+
+impl<K: PartialEq + Debug, V: Debug, const N: usize> Display for TryInsertError<'_, K, V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TryInsertError::Occupied(e) => Display::fmt(e, f),
+            TryInsertError::Full(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl<K: PartialEq + Debug, V: Debug, const N: usize> core::error::Error
+    for OccupiedError<'_, K, V, N>
+{
+}
+
+impl<K: Debug, V: Debug> core::error::Error for CapacityError<K, V> {}
+
+impl<K: PartialEq + Debug, V: Debug, const N: usize> core::error::Error
+    for TryInsertError<'_, K, V, N>
+{
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{Map, TryInsertError};
+
+    #[test]
+    fn displays_occupied_error() {
+        let mut m: Map<u8, i32, 4> = Map::new();
+        m.insert(1, 42);
+        let e = m.try_insert(1, 16).unwrap_err();
+        assert_eq!(
+            "failed to insert 16, key 1 already exists with value 42",
+            format!("{e}")
+        );
+    }
+
+    #[test]
+    fn displays_capacity_error() {
+        let mut m: Map<u8, i32, 1> = Map::new();
+        m.insert(1, 42);
+        let e = m.try_insert(2, 16).unwrap_err();
+        assert!(matches!(e, TryInsertError::Full(_)));
+        assert_eq!("No more keys available in the map", format!("{e}"));
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+
+impl<K: PartialEq, V, const N: usize> Extend<(K, V)> for Map<K, V, N> {
+    /// Insert all pairs, replacing values of existing keys.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are too many pairs, exactly like [`Map::insert`] does.
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<'a, K: PartialEq + Copy, V: Copy, const N: usize> Extend<(&'a K, &'a V)> for Map<K, V, N> {
+    /// Insert copies of all pairs, replacing values of existing keys.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are too many pairs, exactly like [`Map::insert`] does.
+    #[inline]
+    fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(*k, *v);
+        }
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Insert all pairs, replacing values of existing keys, while there
+    /// is space for new keys.
+    ///
+    /// New keys that come when the map is full are ignored, together with
+    /// their values, and the number of them is returned. It never panics,
+    /// unlike [`Extend::extend`].
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, i32, 2> = micromap::Map::new();
+    /// assert_eq!(1, m.extend_truncating([(1, 1), (2, 2), (3, 3), (1, 10)]));
+    /// assert_eq!(10, m[&1]);
+    /// ```
+    #[inline]
+    pub fn extend_truncating<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> usize {
+        let mut dropped = 0;
+        for (k, v) in iter {
+            if self.insert_checked(k, v).is_err() {
+                dropped += 1;
+            }
+        }
+        dropped
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn extends_with_overlapping_keys() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 1);
+        m.insert("two".to_string(), 2);
+        m.extend([("two".to_string(), 16), ("three".to_string(), 3)]);
+        assert_eq!(3, m.len());
+        assert_eq!(16, m["two"]);
+        assert_eq!(3, m["three"]);
+    }
+
+    #[test]
+    fn extends_with_references() {
+        let mut m: Map<u8, i32, 10> = Map::new();
+        m.insert(1, 1);
+        let other: Map<u8, i32, 4> = [(1, 42), (2, 16)].into_iter().collect();
+        m.extend(&other);
+        assert_eq!(2, m.len());
+        assert_eq!(42, m[&1]);
+        assert_eq!(16, m[&2]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        feature = "std",
+        should_panic(expected = "No more keys available in the map")
+    )]
+    #[cfg_attr(not(feature = "std"), should_panic(expected = "index out of bounds"))]
+    #[cfg(debug_assertions)]
+    fn extends_beyond_capacity() {
+        let mut m: Map<u8, i32, 2> = Map::new();
+        m.extend([(1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn extends_truncating_beyond_capacity() {
+        let mut m: Map<u8, i32, 3> = Map::new();
+        m.insert(1, 1);
+        let dropped = m.extend_truncating([(2, 2), (3, 3), (4, 4), (1, 10), (5, 5), (3, 30)]);
+        assert_eq!(2, dropped);
+        assert_eq!(3, m.len());
+        assert_eq!(10, m[&1]);
+        assert_eq!(2, m[&2]);
+        assert_eq!(30, m[&3]);
+        assert!(!m.contains_key(&4));
+        assert_eq!(0, m.extend_truncating([(2, 20)]));
+    }
+}
@@ -18,11 +18,10 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::Map;
+use crate::{CapacityError, Map};
 
 impl<K: PartialEq, V, const N: usize> FromIterator<(K, V)> for Map<K, V, N> {
     #[inline]
-    #[must_use]
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
         let mut m: Self = Self::new();
         for (k, v) in iter {
@@ -34,12 +33,129 @@ impl<K: PartialEq, V, const N: usize> FromIterator<(K, V)> for Map<K, V, N> {
 
 impl<K: PartialEq, V, const N: usize> From<[(K, V); N]> for Map<K, V, N> {
     #[inline]
-    #[must_use]
     fn from(arr: [(K, V); N]) -> Self {
         Self::from_iter(arr)
     }
 }
 
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Make it from keys and values coming separately.
+    ///
+    /// Keys and values are zipped, stopping at the shorter of the two.
+    /// If a key is repeated, its last value wins.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let m: micromap::Map<char, i32, 4> =
+    ///     micromap::Map::from_keys_values(['a', 'b', 'c'], [1, 2]);
+    /// assert_eq!(2, m.len());
+    /// assert_eq!(2, m[&'b']);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are more distinct keys than `N`.
+    #[inline]
+    pub fn from_keys_values<I: IntoIterator<Item = K>, J: IntoIterator<Item = V>>(
+        keys: I,
+        values: J,
+    ) -> Self {
+        keys.into_iter().zip(values).collect()
+    }
+
+    /// Make it from pairs coming from a fallible source.
+    ///
+    /// # Errors
+    ///
+    /// The first error met in the iterator is returned, while the pairs
+    /// collected so far are dropped.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are more distinct keys than `N`.
+    #[inline]
+    pub fn try_from_results<E, I: IntoIterator<Item = Result<(K, V), E>>>(
+        iter: I,
+    ) -> Result<Self, E> {
+        let mut m: Self = Self::new();
+        for p in iter {
+            let (k, v) = p?;
+            m.insert(k, v);
+        }
+        Ok(m)
+    }
+
+    /// Make a map from an iterator, without panicking when it's too long.
+    ///
+    /// Pairs with keys that are already in the map replace their values,
+    /// as in [`Map::insert`].
+    ///
+    /// # Errors
+    ///
+    /// If a new key comes when the map is already full, the iteration stops
+    /// and the rejected pair is returned, while the pairs collected so far
+    /// are dropped.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let m = micromap::Map::<u8, i32, 2>::try_from_iter([(1, 1), (2, 2)]).unwrap();
+    /// assert_eq!(2, m.len());
+    /// let e = micromap::Map::<u8, i32, 2>::try_from_iter([(1, 1), (2, 2), (3, 3)]);
+    /// assert_eq!(3, e.unwrap_err().key);
+    /// ```
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = (K, V)>>(
+        iter: I,
+    ) -> Result<Self, CapacityError<K, V>> {
+        let mut m: Self = Self::new();
+        for (k, v) in iter {
+            m.insert_checked(k, v)
+                .map_err(|(key, value)| CapacityError { key, value })?;
+        }
+        Ok(m)
+    }
+
+    /// Move all pairs into a new map of a different capacity.
+    ///
+    /// The order of pairs in the internal array stays the same.
+    ///
+    /// # Errors
+    ///
+    /// If there are more pairs than `M`, the original map is returned
+    /// back untouched.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, i32, 2> = micromap::Map::new();
+    /// m.insert(1, 42);
+    /// m.insert(2, 16);
+    /// let m: micromap::Map<u8, i32, 8> = m.resize_into().unwrap();
+    /// assert_eq!(8, m.capacity());
+    /// assert!(m.resize_into::<1>().is_err());
+    /// ```
+    #[inline]
+    pub fn resize_into<const M: usize>(mut self) -> Result<Map<K, V, M>, Self> {
+        if self.len > M {
+            return Err(self);
+        }
+        let mut m: Map<K, V, M> = Map::new();
+        for i in 0..self.len {
+            m.item_write(i, self.item_read(i));
+        }
+        #[cfg(feature = "insertion_order")]
+        {
+            m.order[..self.len].copy_from_slice(&self.order[..self.len]);
+            m.next = self.next;
+        }
+        m.len = self.len;
+        self.len = 0;
+        Ok(m)
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -56,7 +172,11 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
+    #[cfg_attr(
+        feature = "std",
+        should_panic(expected = "No more keys available in the map")
+    )]
+    #[cfg_attr(not(feature = "std"), should_panic(expected = "index out of bounds"))]
     #[cfg(debug_assertions)]
     fn from_larger_iter() {
         let vec = Vec::from(TEST_ARRAY);
@@ -82,4 +202,107 @@ mod test {
         assert_eq!(m.len(), 3);
         assert_eq!(m[&2], "thu");
     }
+
+    #[test]
+    fn from_keys_values_of_equal_length() {
+        let m: Map<i32, &str, 5> =
+            Map::from_keys_values(TEST_ARRAY.map(|(k, _)| k), TEST_ARRAY.map(|(_, v)| v));
+        assert_eq!(m, Map::from(TEST_ARRAY));
+    }
+
+    #[test]
+    fn from_keys_values_of_unequal_length() {
+        let m: Map<i32, &str, 5> = Map::from_keys_values(1..=5, ["sun", "mon", "tue"]);
+        assert_eq!(m.len(), 3);
+        assert_eq!(m[&3], "tue");
+        let m: Map<i32, &str, 5> = Map::from_keys_values([1, 2, 1], ["sun", "mon", "tue", "wed"]);
+        assert_eq!(m.len(), 2);
+        assert_eq!(m[&1], "tue");
+    }
+
+    #[test]
+    fn from_all_ok_results() {
+        let m: Result<Map<i32, &str, 5>, String> =
+            Map::try_from_results(TEST_ARRAY.map(Ok::<_, String>));
+        assert_eq!(Ok(Map::from(TEST_ARRAY)), m);
+    }
+
+    #[test]
+    fn from_results_with_error() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let results = [
+            Ok((1, Rc::clone(&v))),
+            Ok((2, Rc::clone(&v))),
+            Err("broken"),
+            Ok((3, Rc::clone(&v))),
+            Err("ignored"),
+        ];
+        let m: Result<Map<i32, Rc<()>, 5>, &str> = Map::try_from_results(results);
+        assert_eq!("broken", m.unwrap_err());
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn grows_full_map() {
+        let m: Map<i32, &str, 5> = TEST_ARRAY.into();
+        let m: Map<i32, &str, 10> = m.resize_into().unwrap();
+        assert_eq!(m.len(), 5);
+        assert_eq!(m.capacity(), 10);
+        assert_eq!(m[&3], "tue");
+        assert_eq!(Map::<i32, &str, 10>::from_iter(TEST_ARRAY), m);
+    }
+
+    #[test]
+    fn fails_to_shrink_too_large_map() {
+        let m: Map<i32, &str, 5> = TEST_ARRAY.into();
+        let back = m.resize_into::<4>().unwrap_err();
+        assert_eq!(back.len(), 5);
+        assert_eq!(back[&5], "thu");
+    }
+
+    #[test]
+    fn shrinks_and_drops_nothing_twice() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<u8, Rc<()>, 8> = Map::new();
+        m.insert(1, Rc::clone(&v));
+        m.insert(2, Rc::clone(&v));
+        let m: Map<u8, Rc<()>, 2> = m.resize_into().unwrap();
+        assert_eq!(Rc::strong_count(&v), 3);
+        drop(m);
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "insertion_order")]
+    fn resizes_with_insertion_order() {
+        let mut m: Map<i32, &str, 5> = TEST_ARRAY.into();
+        m.remove(&1);
+        let m: Map<i32, &str, 10> = m.resize_into().unwrap();
+        let keys: Vec<i32> = m.iter_insertion_order().map(|(k, _)| *k).collect();
+        assert_eq!(vec![2, 3, 4, 5], keys);
+    }
+
+    #[test]
+    fn tries_from_iter_of_exactly_capacity() {
+        let m = Map::<u8, i32, 3>::try_from_iter([(1, 1), (2, 2), (3, 3)]).unwrap();
+        assert_eq!(3, m.len());
+        assert_eq!(3, m[&3]);
+    }
+
+    #[test]
+    fn tries_from_iter_beyond_capacity() {
+        let e = Map::<u8, i32, 3>::try_from_iter([(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)]);
+        assert_eq!(Err(CapacityError { key: 4, value: 4 }), e);
+    }
+
+    #[test]
+    fn tries_from_iter_with_duplicates() {
+        let m = Map::<u8, i32, 2>::try_from_iter([(1, 1), (2, 2), (1, 10), (2, 20)]).unwrap();
+        assert_eq!(10, m[&1]);
+        assert_eq!(20, m[&2]);
+        let e = Map::<u8, i32, 2>::try_from_iter([(1, 1), (1, 2), (2, 2), (3, 3)]);
+        assert_eq!(3, e.unwrap_err().value);
+    }
 }
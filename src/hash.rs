@@ -0,0 +1,85 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use core::hash::{Hash, Hasher};
+
+impl<K: PartialEq + Ord + Hash, V: Hash, const N: usize> Hash for Map<K, V, N> {
+    /// Feed the map into the hasher.
+    ///
+    /// Pairs are hashed in the order of their keys, that's why two
+    /// equal maps always have equal hashes, no matter in which order
+    /// their pairs were inserted.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for i in &self.sorted_indices()[..self.len] {
+            self.item_ref(*i).hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test {
+
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn equal_maps_are_equal_keys_in_hash_map() {
+        let mut first: Map<String, i32, 10> = Map::new();
+        first.insert("one".to_string(), 1);
+        first.insert("two".to_string(), 2);
+        first.insert("three".to_string(), 3);
+        let mut second: Map<String, i32, 10> = Map::new();
+        second.insert("three".to_string(), 3);
+        second.insert("one".to_string(), 1);
+        second.insert("two".to_string(), 2);
+        let mut third: Map<String, i32, 10> = Map::new();
+        third.insert("two".to_string(), 2);
+        third.insert("three".to_string(), 3);
+        third.insert("four".to_string(), 4);
+        third.remove("four");
+        third.insert("one".to_string(), 1);
+        assert_eq!(first, second);
+        assert_eq!(first, third);
+        let mut h: HashMap<Map<String, i32, 10>, usize> = HashMap::new();
+        h.insert(first, 42);
+        assert_eq!(Some(&42), h.get(&second));
+        assert_eq!(Some(&42), h.get(&third));
+    }
+
+    #[test]
+    fn different_maps_are_different_keys_in_hash_map() {
+        let mut h: HashMap<Map<u8, i32, 4>, usize> = HashMap::new();
+        for i in 0..4 {
+            let m: Map<u8, i32, 4> = (0..i).map(|k| (k, i32::from(k))).collect();
+            h.insert(m, usize::from(i));
+        }
+        assert_eq!(4, h.len());
+        let mut m: Map<u8, i32, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(0, 0);
+        assert_eq!(Some(&2), h.get(&m));
+        m.insert(1, 16);
+        assert_eq!(None, h.get(&m));
+    }
+}
@@ -28,7 +28,6 @@ impl<K: PartialEq + Borrow<Q>, Q: PartialEq + ?Sized, V, const N: usize> Index<&
     type Output = V;
 
     #[inline]
-    #[must_use]
     fn index(&self, key: &Q) -> &V {
         self.get(key).expect("No entry found for the key")
     }
@@ -38,7 +37,6 @@ impl<K: PartialEq + Borrow<Q>, Q: PartialEq + ?Sized, V, const N: usize> IndexMu
     for Map<K, V, N>
 {
     #[inline]
-    #[must_use]
     fn index_mut(&mut self, key: &Q) -> &mut V {
         self.get_mut(key).expect("No entry found for the key")
     }
@@ -65,7 +63,8 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "No entry found for the key")]
+    #[allow(clippy::unused_unit)]
     fn wrong_index() -> () {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("first".to_string(), 42);
@@ -25,22 +25,59 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     /// Make an iterator over all pairs.
     #[inline]
     #[must_use]
-    pub fn iter(&self) -> Iter<K, V> {
+    pub fn iter(&self) -> Iter<'_, K, V> {
         self.into_iter()
     }
 
     /// An iterator with mutable references to the values but
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<K, V> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
         self.into_iter()
     }
+
+    /// Make an iterator over all pairs with mutable references to the values,
+    /// together with their indices in the internal array.
+    #[inline]
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (usize, &K, &mut V)> {
+        self.iter_mut().enumerate().map(|(i, (k, v))| (i, k, v))
+    }
+
+    /// Make an iterator over all pairs, copying keys and values.
+    #[inline]
+    pub fn iter_copied(&self) -> impl Iterator<Item = (K, V)> + '_
+    where
+        K: Copy,
+        V: Copy,
+    {
+        self.iter().map(|(k, v)| (*k, *v))
+    }
+
+    /// Make an iterator over all pairs, cloning keys and values.
+    #[inline]
+    pub fn iter_cloned(&self) -> impl Iterator<Item = (K, V)> + '_
+    where
+        K: Clone,
+        V: Clone,
+    {
+        self.iter().map(|(k, v)| (k.clone(), v.clone()))
+    }
+
+    /// Make an iterator over all contiguous windows of pairs, as they
+    /// are stored in the internal array.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    #[inline]
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = &[(K, V)]> {
+        self.as_slice().windows(size)
+    }
 }
 
 impl<'a, K, V> Iterator for Iter<'a, K, V> {
     type Item = (&'a K, &'a V);
 
     #[inline]
-    #[must_use]
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|p| {
             let p = unsafe { p.assume_init_ref() };
@@ -75,9 +112,8 @@ impl<K: PartialEq, V, const N: usize> Iterator for IntoIter<K, V, N> {
     type Item = (K, V);
 
     #[inline]
-    #[must_use]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.map.len > 0 {
+        if self.map.len > self.start {
             self.map.len -= 1;
             Some(self.map.item_read(self.map.len))
         } else {
@@ -87,7 +123,52 @@ impl<K: PartialEq, V, const N: usize> Iterator for IntoIter<K, V, N> {
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.map.len, Some(self.map.len))
+        let len = self.map.len - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|p| {
+            let p = unsafe { p.assume_init_ref() };
+            (&p.0, &p.1)
+        })
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|p| {
+            let p = unsafe { p.assume_init_mut() };
+            (&p.0, &mut p.1)
+        })
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.map.len > self.start {
+            self.start += 1;
+            Some(self.map.item_read(self.start - 1))
+        } else {
+            None
+        }
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> Drop for IntoIter<K, V, N> {
+    fn drop(&mut self) {
+        let len = self.map.len;
+        // the map must not drop anything after this, even if
+        // one of the pairs panics while being dropped
+        self.map.len = 0;
+        for i in self.start..len {
+            self.map.item_drop(i);
+        }
     }
 }
 
@@ -96,7 +177,6 @@ impl<'a, K: PartialEq, V, const N: usize> IntoIterator for &'a Map<K, V, N> {
     type IntoIter = Iter<'a, K, V>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         Iter {
             iter: self.pairs[0..self.len].iter(),
@@ -121,19 +201,21 @@ impl<K: PartialEq, V, const N: usize> IntoIterator for Map<K, V, N> {
     type IntoIter = IntoIter<K, V, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter { map: self }
+        IntoIter {
+            map: self,
+            start: 0,
+        }
     }
 }
 
-impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {
     fn len(&self) -> usize {
         self.iter.len()
     }
@@ -141,13 +223,13 @@ impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {
 
 impl<K: PartialEq, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
     fn len(&self) -> usize {
-        self.map.len
+        self.map.len - self.start
     }
 }
 
-impl<'a, K, V> FusedIterator for Iter<'a, K, V> {}
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
 
-impl<'a, K, V> FusedIterator for IterMut<'a, K, V> {}
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
 
 impl<K: PartialEq, V, const N: usize> FusedIterator for IntoIter<K, V, N> {}
 
@@ -172,6 +254,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::explicit_iter_loop)]
     fn insert_and_iterate() {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("one".to_string(), 42);
@@ -196,6 +279,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::explicit_iter_loop)]
     fn iterate_with_blanks() {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("one".to_string(), 1);
@@ -224,6 +308,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::explicit_iter_loop)]
     fn change_with_iter_mut() {
         let mut m: Map<String, i32, 10> = Map::new();
         m.insert("one".to_string(), 2);
@@ -262,6 +347,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::cast_sign_loss)]
     fn into_iter_drop() {
         use std::rc::Rc;
         let mut m: Map<i32, Rc<()>, 8> = Map::new();
@@ -274,4 +360,127 @@ mod test {
         let _p = m.into_iter().nth(3);
         assert_eq!(Rc::strong_count(&v), 2); // v & p
     }
+
+    #[test]
+    fn iterate_copied() {
+        let mut m: Map<u8, i32, 10> = Map::new();
+        m.insert(1, 42);
+        m.insert(2, 16);
+        let pairs: Vec<(u8, i32)> = m.iter_copied().collect();
+        assert_eq!(m.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(), pairs);
+    }
+
+    #[test]
+    fn iterate_cloned() {
+        let mut m: Map<String, Vec<u8>, 10> = Map::new();
+        m.insert("one".to_string(), vec![1]);
+        m.insert("two".to_string(), vec![2, 2]);
+        let pairs: Vec<(String, Vec<u8>)> = m.iter_cloned().collect();
+        assert_eq!(
+            m.iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect::<Vec<_>>(),
+            pairs
+        );
+    }
+
+    #[test]
+    fn iterate_windows() {
+        let m: Map<u8, i32, 10> = (1..=4).map(|k| (k, i32::from(k) * 10)).collect();
+        let windows: Vec<&[(u8, i32)]> = m.windows(2).collect();
+        assert_eq!(3, windows.len());
+        assert_eq!(&[(1, 10), (2, 20)], windows[0]);
+        assert_eq!(&[(3, 30), (4, 40)], windows[2]);
+        assert!(m.windows(3).all(|w| w.len() == 3 && w[0].0 < w[2].0));
+        assert_eq!(0, m.windows(5).count());
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn iterate_zero_windows() {
+        let m: Map<u8, i32, 10> = Map::new();
+        let _w = m.windows(0);
+    }
+
+    #[test]
+    fn enumerate_mut_sets_slot_indices() {
+        let mut m: Map<String, usize, 10> = Map::new();
+        for k in ["one", "two", "three"] {
+            m.insert(k.to_string(), 42);
+        }
+        m.remove("one");
+        for (i, _k, v) in m.enumerate_mut() {
+            *v = i;
+        }
+        for (i, (_k, v)) in m.iter().enumerate() {
+            assert_eq!(i, *v);
+        }
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn iterate_from_both_ends() {
+        let m: Map<u8, i32, 10> = (0..5).map(|k| (k, i32::from(k))).collect();
+        let forward: Vec<u8> = m.iter().map(|(k, _)| *k).collect();
+        let backward: Vec<u8> = m.iter().rev().map(|(k, _)| *k).collect();
+        assert_eq!(forward.iter().rev().copied().collect::<Vec<_>>(), backward);
+        let mut iter = m.iter();
+        assert_eq!(Some((&0, &0)), iter.next());
+        assert_eq!(Some((&4, &4)), iter.next_back());
+        assert_eq!(3, iter.len());
+    }
+
+    #[test]
+    fn iterate_mut_from_both_ends() {
+        let mut m: Map<u8, i32, 10> = (0..5).map(|k| (k, i32::from(k))).collect();
+        let mut iter = m.iter_mut();
+        let mut seen = vec![];
+        while let Some((k, v)) = iter.next() {
+            *v += 10;
+            seen.push(*k);
+            if let Some((k, v)) = iter.next_back() {
+                *v += 10;
+                seen.push(*k);
+            }
+        }
+        seen.sort_unstable();
+        assert_eq!(vec![0, 1, 2, 3, 4], seen);
+        assert!(m.values().all(|v| *v >= 10));
+    }
+
+    #[test]
+    fn into_iterate_from_both_ends() {
+        let m: Map<u8, i32, 10> = (0..5).map(|k| (k, i32::from(k))).collect();
+        let forward: Vec<u8> = m.clone().into_iter().map(|(k, _)| k).collect();
+        let backward: Vec<u8> = m.clone().into_iter().rev().map(|(k, _)| k).collect();
+        assert_eq!(forward.iter().rev().copied().collect::<Vec<_>>(), backward);
+        let mut iter = m.into_iter();
+        let mut seen = vec![];
+        while let Some((k, _)) = iter.next() {
+            seen.push(k);
+            assert_eq!(5 - seen.len(), iter.len());
+            if let Some((k, _)) = iter.next_back() {
+                seen.push(k);
+            }
+        }
+        assert!(iter.next_back().is_none());
+        seen.sort_unstable();
+        assert_eq!(vec![0, 1, 2, 3, 4], seen);
+    }
+
+    #[test]
+    fn into_iter_from_both_ends_drops_once() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let m: Map<u8, Rc<()>, 8> = (0..6).map(|k| (k, Rc::clone(&v))).collect();
+        let mut iter = m.into_iter();
+        let first = iter.next_back();
+        let last = iter.next();
+        assert_eq!(7, Rc::strong_count(&v));
+        drop(iter);
+        assert_eq!(3, Rc::strong_count(&v));
+        drop(first);
+        drop(last);
+        assert_eq!(1, Rc::strong_count(&v));
+    }
 }
@@ -65,7 +65,7 @@ impl<K: PartialEq, V, const N: usize> Iterator for IntoKeys<K, V, N> {
     }
 }
 
-impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {
     fn len(&self) -> usize {
         self.iter.len()
     }
@@ -77,7 +77,7 @@ impl<K: PartialEq, V, const N: usize> ExactSizeIterator for IntoKeys<K, V, N> {
     }
 }
 
-impl<'a, K, V> FusedIterator for Keys<'a, K, V> {}
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
 
 impl<K: PartialEq, V, const N: usize> FusedIterator for IntoKeys<K, V, N> {}
 
@@ -50,6 +50,11 @@
 #![allow(clippy::multiple_inherent_impl)]
 #![allow(clippy::multiple_crate_versions)]
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "ascii-ci")]
+mod ascii;
+mod bitset;
 mod clone;
 mod ctors;
 mod debug;
@@ -57,14 +62,23 @@ mod display;
 mod drain;
 mod entry;
 mod eq;
+mod error;
+mod extend;
+#[cfg(feature = "defmt")]
+mod format;
 mod from;
+mod hash;
 mod index;
 mod iterators;
 mod keys;
 mod map;
+mod ord;
 #[cfg(feature = "serde")]
 mod serialization;
 mod set;
+mod sorted;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod values;
 
 pub use crate::set::{Set, SetDrain, SetIntoIter, SetIter};
@@ -100,6 +114,38 @@ pub struct Map<K: PartialEq, V, const N: usize> {
     len: usize,
     /// The fixed-size array of key-value pairs.
     pairs: [MaybeUninit<(K, V)>; N],
+    /// The sequence numbers of insertions, one per pair.
+    #[cfg(feature = "insertion_order")]
+    order: [u32; N],
+    /// The sequence number of the next insertion.
+    #[cfg(feature = "insertion_order")]
+    next: u32,
+}
+
+/// What to do when a new key is inserted into a full [`Map`].
+///
+/// It is used by [`Map::insert_with_mode`]. When the map is not full,
+/// or the key is already there, all modes behave exactly like [`Map::insert`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InsertMode {
+    /// Behave like [`Map::insert`], which panics in the "debug" mode.
+    Panic,
+    /// Leave the map as is and give the new pair back.
+    Saturate,
+    /// Evict the pair in the last slot of the internal array, which
+    /// [`Map::iter`] yields last, and put the new pair instead.
+    Overwrite,
+}
+
+/// What [`Map::insert_with_mode`] did with the new pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Inserted<K, V> {
+    /// The key was absent, and the pair was added.
+    Added,
+    /// The key was in the map, and this was its previous value.
+    Replaced(V),
+    /// The map was full, and the new pair took the place of this one.
+    Evicted(K, V),
 }
 
 /// Iterator over the [`Map`].
@@ -115,9 +161,13 @@ pub struct IterMut<'a, K, V> {
 }
 
 /// Into-iterator over the [`Map`].
-#[repr(transparent)]
+///
+/// If it is dropped before being fully consumed, it drops the remaining
+/// pairs, in the order they are stored in the map.
 pub struct IntoIter<K: PartialEq, V, const N: usize> {
     map: Map<K, V, N>,
+    /// Pairs before this index were already taken by `next_back()`.
+    start: usize,
 }
 
 /// An iterator over the values of the [`Map`].
@@ -177,6 +227,38 @@ pub struct VacantEntry<'a, K: 'a + PartialEq, V: 'a, const N: usize> {
     table: &'a mut Map<K, V, N>,
 }
 
+/// The error returned by [`Map::try_insert`] when the key already exists.
+///
+/// Contains the occupied entry, and the value that was not inserted.
+pub struct OccupiedError<'a, K: 'a + PartialEq, V: 'a, const N: usize> {
+    /// The entry in the map that was already occupied.
+    pub entry: OccupiedEntry<'a, K, V, N>,
+    /// The value which was not inserted, because the entry was already occupied.
+    pub value: V,
+}
+
+/// The error returned by [`Map::try_insert`] when the key is absent, but
+/// there is no more space in the map.
+///
+/// Contains the key and the value that were not inserted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CapacityError<K, V> {
+    /// The key which was not inserted.
+    pub key: K,
+    /// The value which was not inserted.
+    pub value: V,
+}
+
+/// The error returned by [`Map::try_insert`].
+#[derive(Debug)]
+pub enum TryInsertError<'a, K: 'a + PartialEq, V: 'a, const N: usize> {
+    /// The key already exists.
+    Occupied(OccupiedError<'a, K, V, N>),
+
+    /// The key is absent and the map is full.
+    Full(CapacityError<K, V>),
+}
+
 /// A draining iterator over the entries of a `Map`.
 ///
 /// This struct is created by the drain method on `Map`. See its documentation for more.
@@ -18,11 +18,17 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::{Drain, Entry, Map, OccupiedEntry, VacantEntry};
+use crate::{
+    CapacityError, Drain, Entry, InsertMode, Inserted, Map, OccupiedEntry, OccupiedError,
+    TryInsertError, VacantEntry,
+};
 use core::borrow::Borrow;
+use core::ops::ControlFlow;
+use internal::{MapValuesGuard, RetainGuard};
 
 mod internal {
     use crate::Map;
+    use core::borrow::Borrow;
 
     impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         /// Internal function to get access via reference to the element in the internal array.
@@ -33,13 +39,13 @@ mod internal {
 
         /// Internal function to get mutable access via reference to the element in the internal array.
         #[inline]
-        pub(crate) fn item_mut(&mut self, i: usize) -> &mut V {
+        pub(crate) const fn item_mut(&mut self, i: usize) -> &mut V {
             &mut unsafe { self.pairs[i].assume_init_mut() }.1
         }
 
         /// Internal function to get access to the element in the internal array.
         #[inline]
-        pub(crate) fn item_read(&mut self, i: usize) -> (K, V) {
+        pub(crate) const fn item_read(&mut self, i: usize) -> (K, V) {
             unsafe { self.pairs[i].assume_init_read() }
         }
 
@@ -51,7 +57,7 @@ mod internal {
 
         /// Internal function to get access to the element in the internal array.
         #[inline]
-        pub(crate) fn item_write(&mut self, i: usize, val: (K, V)) {
+        pub(crate) const fn item_write(&mut self, i: usize, val: (K, V)) {
             self.pairs[i].write(val);
         }
 
@@ -64,26 +70,279 @@ mod internal {
             if i != self.len {
                 let value = self.item_read(self.len);
                 self.item_write(i, value);
+                self.order_move(self.len, i);
             }
         }
 
         /// Remove an index (by swapping the last one here and reducing the length)
         #[inline]
-        pub(crate) fn remove_index_read(&mut self, i: usize) -> (K, V) {
+        pub(crate) const fn remove_index_read(&mut self, i: usize) -> (K, V) {
             let result = self.item_read(i);
 
             self.len -= 1;
             if i != self.len {
                 let value = self.item_read(self.len);
                 self.item_write(i, value);
+                self.order_move(self.len, i);
             }
 
             result
         }
+
+        /// Remove an index (by shifting all pairs after it one slot to the left)
+        #[inline]
+        pub(crate) const fn remove_index_shift(&mut self, i: usize) -> (K, V) {
+            let result = self.item_read(i);
+            let tail = self.len - i - 1;
+            // SAFETY: both ranges are inside the array, and the pairs are only
+            // moved bitwise, so nothing is dropped or duplicated: the slot of the
+            // last pair is considered uninitialized after `len` shrinks
+            unsafe {
+                let p = self.pairs.as_mut_ptr();
+                core::ptr::copy(p.add(i + 1), p.add(i), tail);
+                #[cfg(feature = "insertion_order")]
+                {
+                    let o = self.order.as_mut_ptr();
+                    core::ptr::copy(o.add(i + 1), o.add(i), tail);
+                }
+            }
+            self.len -= 1;
+            result
+        }
+
+        /// Internal function to move the pair to the first slot, shifting
+        /// all pairs before it one slot to the right.
+        #[inline]
+        pub(crate) fn move_to_front(&mut self, i: usize) {
+            self.pairs[..=i].rotate_right(1);
+            #[cfg(feature = "insertion_order")]
+            self.order[..=i].rotate_right(1);
+        }
+
+        /// Internal function to register a new key, just placed into the given slot.
+        #[cfg(feature = "insertion_order")]
+        #[inline]
+        pub(crate) fn order_new(&mut self, i: usize) {
+            if self.next == u32::MAX {
+                self.order_compact();
+            }
+            self.order[i] = self.next;
+            self.next += 1;
+        }
+
+        /// Internal function to register a new key, just placed into the given slot.
+        #[cfg(not(feature = "insertion_order"))]
+        #[inline]
+        #[allow(clippy::unused_self)]
+        pub(crate) const fn order_new(&self, _i: usize) {}
+
+        /// Internal function to register the move of a pair from one slot to another.
+        #[cfg(feature = "insertion_order")]
+        #[inline]
+        pub(crate) const fn order_move(&mut self, from: usize, to: usize) {
+            self.order[to] = self.order[from];
+        }
+
+        /// Internal function to register the move of a pair from one slot to another.
+        #[cfg(not(feature = "insertion_order"))]
+        #[inline]
+        #[allow(clippy::unused_self)]
+        pub(crate) const fn order_move(&self, _from: usize, _to: usize) {}
+
+        /// Internal function to swap two slots, together with their insertion order.
+        #[inline]
+        pub(crate) const fn swap_slots(&mut self, i: usize, j: usize) {
+            self.pairs.swap(i, j);
+            #[cfg(feature = "insertion_order")]
+            self.order.swap(i, j);
+        }
+
+        /// Internal function to move a just found pair to the first slot,
+        /// so that the next lookup of the same key is faster.
+        #[cfg(feature = "mtf")]
+        #[inline]
+        pub(crate) const fn promote(&mut self, i: usize) -> usize {
+            self.swap_slots(0, i);
+            0
+        }
+
+        /// Internal function to move a just found pair to the first slot,
+        /// so that the next lookup of the same key is faster.
+        #[cfg(not(feature = "mtf"))]
+        #[inline]
+        #[allow(clippy::unused_self)]
+        pub(crate) const fn promote(&self, i: usize) -> usize {
+            i
+        }
+
+        /// Internal function to renumber insertions from zero, keeping their order.
+        #[cfg(feature = "insertion_order")]
+        #[allow(clippy::cast_possible_truncation)]
+        fn order_compact(&mut self) {
+            let indices = self.insertion_indices();
+            for (seq, i) in indices[..self.len].iter().enumerate() {
+                self.order[*i] = seq as u32;
+            }
+            self.next = self.len as u32;
+        }
+
+        /// Internal function to get the indices of all pairs, in the order of insertion.
+        ///
+        /// Only the first `len` elements of the returned array are meaningful.
+        #[cfg(feature = "insertion_order")]
+        pub(crate) fn insertion_indices(&self) -> [usize; N] {
+            let mut indices: [usize; N] = core::array::from_fn(|i| i);
+            indices[..self.len].sort_unstable_by_key(|i| self.order[*i]);
+            indices
+        }
+
+        /// Internal function to find the index of the pair with the given key.
+        #[inline]
+        pub(crate) fn index_of<Q: PartialEq + ?Sized>(&self, k: &Q) -> Option<usize>
+        where
+            K: Borrow<Q>,
+        {
+            (0..self.len).find(|i| self.item_ref(*i).0.borrow() == k)
+        }
+
+        /// Internal function to get mutable references to many pairs at once.
+        ///
+        /// The keys are handed out mutably too, which is why it's not public:
+        /// the caller must not change them in a way that affects equality.
+        ///
+        /// # Panics
+        ///
+        /// If any two keys point to the same pair.
+        pub(crate) fn disjoint_pairs_mut<Q: PartialEq + ?Sized, const J: usize>(
+            &mut self,
+            ks: [&Q; J],
+        ) -> [Option<&mut (K, V)>; J]
+        where
+            K: Borrow<Q>,
+        {
+            let indices = ks.map(|k| self.index_of(k));
+            let hits = indices.iter().filter(|i| i.is_some()).count();
+            assert!(hits <= self.len, "Overlapping keys");
+            for (j, i) in indices.iter().enumerate() {
+                assert!(i.is_none() || !indices[..j].contains(i), "Overlapping keys");
+            }
+            let pairs = self.pairs.as_mut_ptr();
+            indices.map(|i| {
+                i.map(|i| {
+                    // SAFETY: the index is in the initialized prefix of the array,
+                    // and all found indices are distinct, as checked above
+                    unsafe { (*pairs.add(i)).assume_init_mut() }
+                })
+            })
+        }
+
+        /// Internal function to get the indices of all pairs, sorted by their keys.
+        ///
+        /// Only the first `len` elements of the returned array are meaningful.
+        #[inline]
+        pub(crate) fn sorted_indices(&self) -> [usize; N]
+        where
+            K: Ord,
+        {
+            let mut indices: [usize; N] = core::array::from_fn(|i| i);
+            indices[..self.len]
+                .sort_unstable_by(|a, b| self.item_ref(*a).0.cmp(&self.item_ref(*b).0));
+            indices
+        }
+    }
+
+    /// Internal guard of [`Map::retain_count`], which moves the pairs not
+    /// visited yet right after the retained ones, if the predicate panics.
+    pub struct RetainGuard<'a, K: PartialEq, V, const N: usize> {
+        pub map: &'a mut Map<K, V, N>,
+        pub visited: usize,
+        pub len: usize,
+    }
+
+    impl<K: PartialEq, V, const N: usize> Drop for RetainGuard<'_, K, V, N> {
+        fn drop(&mut self) {
+            for i in self.visited..self.len {
+                let to = self.map.len;
+                let p = self.map.item_read(i);
+                self.map.item_write(to, p);
+                self.map.order_move(i, to);
+                self.map.len += 1;
+            }
+        }
+    }
+
+    /// Internal guard of [`Map::map_values`], which drops the pairs not
+    /// visited yet, if the function panics.
+    pub struct MapValuesGuard<'a, K: PartialEq, V, const N: usize> {
+        pub map: &'a mut Map<K, V, N>,
+        pub visited: usize,
+        pub len: usize,
+    }
+
+    impl<K: PartialEq, V, const N: usize> Drop for MapValuesGuard<'_, K, V, N> {
+        fn drop(&mut self) {
+            for i in self.visited..self.len {
+                self.map.item_drop(i);
+            }
+        }
     }
 }
 
 impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// How many bytes of stack the map takes.
+    pub const STACK_BYTES: usize = core::mem::size_of::<Self>();
+
+    /// Same as [`Map::STACK_BYTES`], usable where a function is needed.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// type M = micromap::Map<u64, u64, 4>;
+    /// assert!(M::stack_size() >= 4 * 16);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn stack_size() -> usize {
+        Self::STACK_BYTES
+    }
+
+    /// Get all pairs as a slice, without copying them.
+    ///
+    /// The order of pairs is not specified and changes when pairs are removed.
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, i32, 4> = micromap::Map::new();
+    /// m.insert(1, 42);
+    /// assert_eq!(&[(1, 42)], m.as_slice());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_slice(&self) -> &[(K, V)] {
+        // SAFETY: the pointer is valid and aligned, since it comes from the array,
+        // and `len <= N`. `MaybeUninit<T>` has the same layout as `T`. All pairs
+        // in `0..len` are initialized: `item_write` is only called on `len` before
+        // it grows or on a slot just emptied by `item_read`, while pairs are dropped
+        // or read out of the prefix only together with shrinking `len` or moving
+        // another pair into their slot. The slice borrows `self`, so nothing can
+        // change the prefix while it is alive.
+        unsafe { core::slice::from_raw_parts(self.pairs.as_ptr().cast::<(K, V)>(), self.len) }
+    }
+
+    /// Get all pairs as a slice, exactly like [`Map::as_slice`] does,
+    /// under the name of the internal array.
+    #[inline]
+    #[must_use]
+    pub const fn pairs(&self) -> &[(K, V)] {
+        self.as_slice()
+    }
+
+    /// Get the number of pairs, which is the length of [`Map::as_slice`].
+    #[inline]
+    #[must_use]
+    pub const fn pairs_len(&self) -> usize {
+        self.len
+    }
+
     /// Get its total capacity.
     #[inline]
     #[must_use]
@@ -91,6 +350,14 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         N
     }
 
+    /// Do nothing, since the capacity is fixed.
+    ///
+    /// It exists only to make porting from [`std::collections::HashMap`]
+    /// easier, and is deprecated to remind that the call may be removed.
+    #[inline]
+    #[deprecated(note = "micromap capacity is fixed; this is a no-op")]
+    pub const fn shrink_to_fit(&self) {}
+
     /// Is it empty?
     #[inline]
     #[must_use]
@@ -107,7 +374,7 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
 
     /// Clears the map, returning all key-value pairs as an iterator. Keeps the allocated memory for reuse.
     ///
-    /// If the returned iterator is dropped before being fully consumed, it drops the remaining key-value pairs. The returned iterator keeps a mutable borrow on the map to optimize its implementation.
+    /// If the returned iterator is dropped before being fully consumed, it drops the remaining key-value pairs, in the order they are stored in the map. The returned iterator keeps a mutable borrow on the map to optimize its implementation.
     pub fn drain(&mut self) -> Drain<'_, K, V> {
         let drain = Drain {
             iter: self.pairs[0..self.len].iter_mut(),
@@ -132,6 +399,36 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         false
     }
 
+    /// Which of these keys does the map contain?
+    ///
+    /// Returns a bitmask, where the i-th bit is set if the i-th key
+    /// is in the map. Only the first 64 keys are checked, the rest
+    /// are ignored.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, i32, 10> = micromap::Map::new();
+    /// m.insert(1, 42);
+    /// m.insert(3, 16);
+    /// assert_eq!(0b101, m.contains_keys_mask(&[1, 2, 3]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_keys_mask<'a, Q, I>(&self, keys: I) -> u64
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + 'a + ?Sized,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        keys.into_iter()
+            .take(64)
+            .enumerate()
+            .fold(0, |mask, (i, k)| {
+                mask | (u64::from(self.contains_key(k)) << i)
+            })
+    }
+
     /// Remove by key.
     #[inline]
     pub fn remove<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<V>
@@ -161,6 +458,74 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         existing_value
     }
 
+    /// Insert a single pair into the map, only if the key is absent
+    /// and there is enough space.
+    ///
+    /// Returns a mutable reference to the inserted value.
+    ///
+    /// # Errors
+    ///
+    /// If the key is already in the map, [`TryInsertError::Occupied`] is
+    /// returned, with the existing entry and the rejected value. If the key is
+    /// absent and the map is full, [`TryInsertError::Full`] is returned, with
+    /// the rejected key and value.
+    #[inline]
+    pub fn try_insert(&mut self, k: K, v: V) -> Result<&mut V, TryInsertError<'_, K, V, N>> {
+        if let Some(index) = self.index_of(&k) {
+            return Err(TryInsertError::Occupied(OccupiedError {
+                entry: OccupiedEntry { index, table: self },
+                value: v,
+            }));
+        }
+        if self.len == N {
+            return Err(TryInsertError::Full(CapacityError { key: k, value: v }));
+        }
+        let i = self.len;
+        self.item_write(i, (k, v));
+        self.order_new(i);
+        self.len += 1;
+        Ok(self.item_mut(i))
+    }
+
+    /// Insert a single pair into the map, never panicking on overflow.
+    ///
+    /// Returns `Ok(Some(old))` if the key was in the map and its value was
+    /// replaced, or `Ok(None)` if the pair was added.
+    ///
+    /// # Errors
+    ///
+    /// If the key is absent and the map is full, the pair is returned back.
+    #[inline]
+    pub fn insert_checked(&mut self, k: K, v: V) -> Result<Option<V>, (K, V)> {
+        if let Some(i) = self.index_of(&k) {
+            let (_, old) = self.item_read(i);
+            self.item_write(i, (k, v));
+            return Ok(Some(old));
+        }
+        if self.len == N {
+            return Err((k, v));
+        }
+        self.item_write(self.len, (k, v));
+        self.order_new(self.len);
+        self.len += 1;
+        Ok(None)
+    }
+
+    /// Insert a single pair into the map, returning the index of its slot
+    /// in the internal array, together with the previous value, if any.
+    ///
+    /// The index stays the same until the next removal from the map, or,
+    /// with the `mtf` feature enabled, until the next [`Map::get_mut`].
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are too many pairs in the map already, exactly
+    /// like [`Map::insert`] does.
+    #[inline]
+    pub fn insert_full(&mut self, k: K, v: V) -> (usize, Option<V>) {
+        self.insert_i(k, v)
+    }
+
     #[inline]
     pub(crate) fn insert_i(&mut self, k: K, v: V) -> (usize, Option<V>) {
         let mut target = self.len;
@@ -182,12 +547,119 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         }
         self.item_write(target, (k, v));
         if target == self.len {
+            self.order_new(target);
             self.len += 1;
         }
 
         (target, existing_value)
     }
 
+    /// Insert a single pair into the map, deciding what to do on overflow
+    /// according to the provided [`InsertMode`].
+    ///
+    /// Existing keys are always updated, returning [`Inserted::Replaced`],
+    /// and new keys are added while there is room, returning [`Inserted::Added`].
+    /// When the map is full and the key is new, [`InsertMode::Overwrite`]
+    /// evicts the pair in the last slot of the internal array, which
+    /// [`Map::iter`] yields last, puts the new pair instead, and returns
+    /// the evicted one as [`Inserted::Evicted`].
+    ///
+    /// # Errors
+    ///
+    /// If the key is absent and the map is full, [`InsertMode::Saturate`]
+    /// returns the new pair back. So does [`InsertMode::Overwrite`], if the
+    /// map has zero capacity and there is nothing to evict.
+    ///
+    /// # Panics
+    ///
+    /// With [`InsertMode::Panic`], it may panic exactly like [`Map::insert`] does.
+    #[inline]
+    pub fn insert_with_mode(
+        &mut self,
+        k: K,
+        v: V,
+        mode: InsertMode,
+    ) -> Result<Inserted<K, V>, (K, V)> {
+        let result = match mode {
+            InsertMode::Panic => Ok(self.insert(k, v)),
+            InsertMode::Saturate => self.insert_checked(k, v),
+            InsertMode::Overwrite => match self.insert_checked(k, v) {
+                Err((k, v)) if N > 0 => {
+                    let (ek, ev) = self.remove_index_read(N - 1);
+                    self.insert(k, v);
+                    return Ok(Inserted::Evicted(ek, ev));
+                }
+                result => result,
+            },
+        };
+        result.map(|old| old.map_or(Inserted::Added, Inserted::Replaced))
+    }
+
+    /// Insert a single pair into the map, only if the key is not there yet.
+    ///
+    /// Returns a mutable reference to the inserted value. If the key is
+    /// already in the map, nothing is changed and the rejected pair is
+    /// returned back as an error.
+    ///
+    /// # Errors
+    ///
+    /// If the key is already present in the map, the pair is returned back.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are too many pairs in the map already, exactly
+    /// like [`Map::insert`] does.
+    #[inline]
+    pub fn insert_if_absent(&mut self, k: K, v: V) -> Result<&mut V, (K, V)> {
+        if self.contains_key(&k) {
+            return Err((k, v));
+        }
+        #[cfg(feature = "std")]
+        debug_assert!(self.len < N, "No more keys available in the map");
+        let i = self.len;
+        self.item_write(i, (k, v));
+        self.order_new(i);
+        self.len += 1;
+        Ok(self.item_mut(i))
+    }
+
+    /// Get the index and a mutable reference to the value of the key,
+    /// inserting the value made by `make` first, if the key is absent.
+    ///
+    /// The index is the position of the pair in the internal array, which
+    /// stays the same until the next removal from the map, or, with the
+    /// `mtf` feature enabled, until the next [`Map::get_mut`].
+    ///
+    /// For example, this is how a string interner may look like:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<&str, usize, 10> = micromap::Map::new();
+    /// for s in ["foo", "bar", "foo"] {
+    ///     let next = m.len();
+    ///     m.intern(s, || next);
+    /// }
+    /// assert_eq!((1, &mut 1), m.intern("bar", || 42));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// It may panic if the key is absent and there are too many pairs in the map already.
+    #[inline]
+    pub fn intern<F: FnOnce() -> V>(&mut self, k: K, make: F) -> (usize, &mut V) {
+        let i = if let Some(i) = self.index_of(&k) {
+            i
+        } else {
+            #[cfg(feature = "std")]
+            debug_assert!(self.len < N, "No more keys available in the map");
+            let i = self.len;
+            self.item_write(i, (k, make()));
+            self.order_new(i);
+            self.len += 1;
+            i
+        };
+        (i, self.item_mut(i))
+    }
+
     /// Get a reference to a single value.
     #[inline]
     #[must_use]
@@ -204,103 +676,720 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         None
     }
 
-    /// Get a mutable reference to a single value.
+    /// Get a reference to a single value, comparing keys with the function.
     ///
-    /// # Panics
+    /// The function gets each stored key and the needle, and returns `true`
+    /// when they match. It makes lookups with a custom equality possible,
+    /// for example case-insensitive, without changing `PartialEq` of `K`.
     ///
-    /// If can't turn it into a mutable state.
+    /// ```
+    /// let mut m: micromap::Map<&str, i32, 4> = micromap::Map::new();
+    /// m.insert("Foo", 42);
+    /// let v = m.get_by_cmp("FOO", |k, n| k.eq_ignore_ascii_case(n));
+    /// assert_eq!(Some(&42), v);
+    /// ```
     #[inline]
     #[must_use]
-    pub fn get_mut<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<&mut V>
+    pub fn get_by_cmp<Q: ?Sized, F: Fn(&K, &Q) -> bool>(&self, needle: &Q, eq: F) -> Option<&V> {
+        self.iter().find(|(k, _)| eq(k, needle)).map(|p| p.1)
+    }
+
+    /// Turn into a map with the same keys and values made by the function.
+    ///
+    /// Keys are moved, not cloned, and stay in the same slots of the internal
+    /// array. If the function panics, all pairs are dropped.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, u32, 4> = micromap::Map::new();
+    /// m.insert(1, 42);
+    /// let m = m.map_values(|k, v| format!("{k}:{v}"));
+    /// assert_eq!("1:42", m[&1]);
+    /// ```
+    #[inline]
+    pub fn map_values<W, F: FnMut(&K, V) -> W>(mut self, mut f: F) -> Map<K, W, N> {
+        let mut result: Map<K, W, N> = Map::new();
+        let len = self.len;
+        self.len = 0;
+        #[cfg(feature = "insertion_order")]
+        {
+            result.order[..len].copy_from_slice(&self.order[..len]);
+            result.next = self.next;
+        }
+        let mut guard = MapValuesGuard {
+            map: &mut self,
+            visited: 0,
+            len,
+        };
+        while guard.visited < len {
+            let i = guard.visited;
+            let (k, v) = guard.map.item_read(i);
+            guard.visited += 1;
+            let value = f(&k, v);
+            result.item_write(i, (k, value));
+            result.len += 1;
+        }
+        result
+    }
+
+    /// Does the map contain this value?
+    ///
+    /// It is a linear scan over all values, in O(n).
+    #[inline]
+    #[must_use]
+    pub fn contains_value<W: PartialEq + ?Sized>(&self, v: &W) -> bool
     where
-        K: Borrow<Q>,
+        V: Borrow<W>,
+    {
+        self.find_key_by_value(v).is_some()
+    }
+
+    /// Find the first key with this value.
+    ///
+    /// It is a linear scan over all values, in the order they are stored.
+    #[inline]
+    #[must_use]
+    pub fn find_key_by_value<W: PartialEq + ?Sized>(&self, v: &W) -> Option<&K>
+    where
+        V: Borrow<W>,
     {
         for i in 0..self.len {
             let p = self.item_ref(i);
-            if p.0.borrow() == k {
-                return Some(self.item_mut(i));
+            if p.1.borrow() == v {
+                return Some(&p.0);
             }
         }
         None
     }
 
-    /// Remove all pairs from it, but keep the space intact for future use.
+    /// Get a reference to a single value or the provided default,
+    /// if there is no such key in the map.
     #[inline]
-    pub fn clear(&mut self) {
-        for i in 0..self.len {
-            self.item_drop(i);
-        }
-        self.len = 0;
+    #[must_use]
+    pub fn get_or<'a, Q: PartialEq + ?Sized>(&'a self, k: &Q, default: &'a V) -> &'a V
+    where
+        K: Borrow<Q>,
+    {
+        self.get(k).unwrap_or(default)
     }
 
-    /// Retains only the elements specified by the predicate.
+    /// Get a clone of a single value or the default value,
+    /// if there is no such key in the map.
     #[inline]
-    pub fn retain<F: Fn(&K, &V) -> bool>(&mut self, f: F) {
-        let mut i = 0;
-        while i < self.len {
-            let p = self.item_ref(i);
-            if f(&p.0, &p.1) {
-                // do not remove -> next index
-                i += 1;
-            } else {
-                self.remove_index_drop(i);
-                // recheck the same index
-            }
-        }
+    #[must_use]
+    pub fn get_or_default<Q: PartialEq + ?Sized>(&self, k: &Q) -> V
+    where
+        K: Borrow<Q>,
+        V: Default + Clone,
+    {
+        self.get(k).cloned().unwrap_or_default()
     }
 
-    /// Returns the key-value pair corresponding to the supplied key.
+    /// Get a mutable reference to a single value, inserting the default
+    /// value first, if there is no such key in the map.
+    ///
+    /// It is the same as `entry(k).or_default()`.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if the key is absent and there are too many pairs in the map already.
     #[inline]
-    pub fn get_key_value<Q: PartialEq + ?Sized>(&self, k: &Q) -> Option<(&K, &V)>
+    pub fn get_or_insert_default(&mut self, k: K) -> &mut V
     where
-        K: Borrow<Q>,
+        V: Default,
     {
-        for i in 0..self.len {
-            let p = self.item_ref(i);
-            if p.0.borrow() == k {
-                return Some((&p.0, &p.1));
-            }
-        }
-        None
+        self.entry(k).or_default()
     }
 
-    /// Removes a key from the map, returning the stored key and value if the
-    /// key was previously in the map.
+    /// Get a mutable reference to a single value.
+    ///
+    /// With the `mtf` feature enabled, the found pair is swapped with
+    /// the one in the first slot of the internal array, so that lookups
+    /// of frequently used keys get faster.
+    ///
+    /// # Panics
+    ///
+    /// If can't turn it into a mutable state.
     #[inline]
-    pub fn remove_entry<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<(K, V)>
+    #[must_use]
+    pub fn get_mut<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<&mut V>
     where
         K: Borrow<Q>,
     {
         for i in 0..self.len {
             let p = self.item_ref(i);
             if p.0.borrow() == k {
-                return Some(self.remove_index_read(i));
+                let i = self.promote(i);
+                return Some(self.item_mut(i));
             }
         }
         None
     }
 
-    pub fn entry(&mut self, k: K) -> Entry<'_, K, V, N> {
-        for i in 0..self.len {
-            let p = self.item_ref(i);
-            if p.0 == k {
-                return Entry::Occupied(OccupiedEntry {
-                    index: i,
-                    table: self,
-                });
+    /// Replace values of many keys at once, ignoring keys that are absent.
+    ///
+    /// Returns how many values were replaced. New keys are never inserted.
+    #[inline]
+    pub fn update_values<'a, Q, I>(&mut self, updates: I) -> usize
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + 'a + ?Sized,
+        I: IntoIterator<Item = (&'a Q, V)>,
+    {
+        let mut count = 0;
+        for (k, v) in updates {
+            if let Some(i) = self.index_of(k) {
+                *self.item_mut(i) = v;
+                count += 1;
             }
         }
-        Entry::Vacant(VacantEntry {
-            key: k,
-            table: self,
-        })
+        count
     }
-}
 
-#[cfg(test)]
-mod test {
+    /// Get mutable references to many values at once.
+    ///
+    /// Returns an array of the same length as `ks`, where the i-th element
+    /// is a mutable reference to the value of the i-th key, or `None` if
+    /// there is no such key in the map.
+    ///
+    /// Every key is looked up with a linear scan, which makes it O(J·n).
+    /// Then, all found pairs are checked for overlapping, which is O(J²),
+    /// unless there are more hits than pairs in the map: in this case
+    /// the overlap is obvious and no pairwise check is needed.
+    ///
+    /// # Panics
+    ///
+    /// If any two keys point to the same pair.
+    #[inline]
+    pub fn get_disjoint_mut<Q: PartialEq + ?Sized, const J: usize>(
+        &mut self,
+        ks: [&Q; J],
+    ) -> [Option<&mut V>; J]
+    where
+        K: Borrow<Q>,
+    {
+        self.disjoint_pairs_mut(ks).map(|p| p.map(|p| &mut p.1))
+    }
 
-    use super::*;
+    /// Get mutable references to many values at once, only if all keys
+    /// are in the map.
+    ///
+    /// It works exactly like [`Map::get_disjoint_mut`], but returns `None`
+    /// if at least one key is absent.
+    ///
+    /// # Panics
+    ///
+    /// If any two keys point to the same pair.
+    #[inline]
+    pub fn get_disjoint_mut_present<Q: PartialEq + ?Sized, const J: usize>(
+        &mut self,
+        ks: [&Q; J],
+    ) -> Option<[&mut V; J]>
+    where
+        K: Borrow<Q>,
+    {
+        let found = self.get_disjoint_mut(ks);
+        if found.iter().all(Option::is_some) {
+            Some(found.map(Option::unwrap))
+        } else {
+            None
+        }
+    }
+
+    /// Move the pair with this key to the first slot of the internal array,
+    /// shifting all pairs before it one slot further.
+    ///
+    /// Returns `true` if the key was found. Moving keys to the front
+    /// every time they are used keeps them ordered by recency, which
+    /// is enough to implement an LRU cache, unless the `mtf` feature
+    /// is enabled and [`Map::get_mut`] reorders the pairs too:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<char, i32, 4> = micromap::Map::new();
+    /// m.insert('a', 1);
+    /// m.insert('b', 2);
+    /// m.insert('c', 3);
+    /// assert!(m.move_key_to_front(&'b'));
+    /// assert_eq!(Some((&'b', &2)), m.iter().next());
+    /// ```
+    #[inline]
+    pub fn move_key_to_front<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        let Some(i) = self.index_of(k) else {
+            return false;
+        };
+        self.move_to_front(i);
+        true
+    }
+
+    /// Rotate the pairs in place, so that the pair in slot `n` becomes
+    /// the first one and the first `n` pairs go to the end.
+    ///
+    /// The contents of the map don't change, only the order of iteration
+    /// and scanning. Calling it between lookups lets keys take turns
+    /// being scanned first:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<char, i32, 4> = micromap::Map::new();
+    /// m.insert('a', 1);
+    /// m.insert('b', 2);
+    /// m.insert('c', 3);
+    /// m.rotate_left(1);
+    /// assert_eq!(Some((&'b', &2)), m.iter().next());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `n` is larger than the length of the map.
+    #[inline]
+    pub fn rotate_left(&mut self, n: usize) {
+        self.pairs[..self.len].rotate_left(n);
+        #[cfg(feature = "insertion_order")]
+        self.order[..self.len].rotate_left(n);
+    }
+
+    /// Rotate the pairs in place, so that the last `n` pairs go
+    /// to the beginning.
+    ///
+    /// See [`Map::rotate_left`].
+    ///
+    /// # Panics
+    ///
+    /// If `n` is larger than the length of the map.
+    #[inline]
+    pub fn rotate_right(&mut self, n: usize) {
+        self.pairs[..self.len].rotate_right(n);
+        #[cfg(feature = "insertion_order")]
+        self.order[..self.len].rotate_right(n);
+    }
+
+    /// Remove the pair from the last slot of the internal array and return it.
+    ///
+    /// Together with [`Map::move_key_to_front`] it evicts the least
+    /// recently used pair. With the `mtf` feature enabled, this is not
+    /// true anymore, since [`Map::get_mut`] swaps pairs too.
+    #[inline]
+    pub const fn evict_back(&mut self) -> Option<(K, V)> {
+        if self.len == 0 {
+            return None;
+        }
+        Some(self.remove_index_read(self.len - 1))
+    }
+
+    /// Remove some pair and return it, in O(1).
+    ///
+    /// It is the pair in the last slot of the internal array, the same one
+    /// that [`Map::peek_last`] returns and [`Map::evict_back`] removes.
+    /// Popping until `None` returns all pairs, each of them once.
+    #[inline]
+    pub const fn pop(&mut self) -> Option<(K, V)> {
+        self.evict_back()
+    }
+
+    /// Get the pair from the last slot of the internal array, which
+    /// [`Map::pop`] would remove.
+    #[inline]
+    #[must_use]
+    pub const fn peek_last(&self) -> Option<(&K, &V)> {
+        if self.len == 0 {
+            return None;
+        }
+        let p = self.item_ref(self.len - 1);
+        Some((&p.0, &p.1))
+    }
+
+    /// Get the pair with the smallest result of the function.
+    ///
+    /// If several pairs are equally minimum, the first stored one is returned.
+    #[inline]
+    pub fn entry_min_by_key<B: Ord, F: FnMut(&K, &V) -> B>(&self, mut f: F) -> Option<(&K, &V)> {
+        self.iter().min_by_key(|(k, v)| f(k, v))
+    }
+
+    /// Get the pair with the largest result of the function.
+    ///
+    /// If several pairs are equally maximum, the last stored one is returned.
+    #[inline]
+    pub fn entry_max_by_key<B: Ord, F: FnMut(&K, &V) -> B>(&self, mut f: F) -> Option<(&K, &V)> {
+        self.iter().max_by_key(|(k, v)| f(k, v))
+    }
+
+    /// Move all keys and values into two vectors, in matching order.
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn into_columns(self) -> (Vec<K>, Vec<V>) {
+        self.into_iter().unzip()
+    }
+
+    /// Clone all pairs into a vector, sorted by keys.
+    ///
+    /// The map itself is not modified.
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn to_sorted_vec(&self) -> Vec<(K, V)>
+    where
+        K: Ord + Clone,
+        V: Clone,
+    {
+        self.sorted_indices()[..self.len]
+            .iter()
+            .map(|i| self.item_ref(*i).clone())
+            .collect()
+    }
+
+    /// Print all keys into a string, separated by the separator.
+    ///
+    /// Keys go in the order they are stored in the map.
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn keys_joined(&self, sep: &str) -> String
+    where
+        K: core::fmt::Display,
+    {
+        use core::fmt::Write;
+        let mut s = String::new();
+        for (i, k) in self.keys().enumerate() {
+            if i > 0 {
+                s.push_str(sep);
+            }
+            let _ = write!(s, "{k}");
+        }
+        s
+    }
+
+    /// Get up to `J` pairs with the largest values, in descending order.
+    ///
+    /// If there are less than `J` pairs in the map, the remaining
+    /// elements of the array are `None`. Pairs with equal values go
+    /// in the order they are stored in the map. It is O(J·n).
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<char, i32, 10> = micromap::Map::new();
+    /// m.insert('a', 16);
+    /// m.insert('b', 42);
+    /// m.insert('c', 8);
+    /// assert_eq!([Some((&'b', &42)), Some((&'a', &16))], m.top_k_by_value::<2>());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn top_k_by_value<const J: usize>(&self) -> [Option<(&K, &V)>; J]
+    where
+        V: Ord,
+    {
+        let mut top: [Option<(&K, &V)>; J] = [None; J];
+        for (k, v) in self {
+            let pos = top.iter().position(|t| t.is_none_or(|(_, w)| v > w));
+            if let Some(pos) = pos {
+                top[pos..].rotate_right(1);
+                top[pos] = Some((k, v));
+            }
+        }
+        top
+    }
+
+    /// Remove all pairs from it, but keep the space intact for future use.
+    ///
+    /// Pairs are dropped in the order they are stored in the map.
+    #[inline]
+    pub fn clear(&mut self) {
+        let len = self.len;
+        self.len = 0;
+        for i in 0..len {
+            self.item_drop(i);
+        }
+    }
+
+    /// Compare with another map, treating absent keys as having the default value.
+    ///
+    /// For example, `{a: 0}` equals `{}` when the default is `0`.
+    ///
+    /// ```
+    /// let mut m1: micromap::Map<char, i32, 10> = micromap::Map::new();
+    /// let m2: micromap::Map<char, i32, 4> = micromap::Map::new();
+    /// m1.insert('a', 0);
+    /// assert!(m1.eq_with_default(&m2, &0));
+    /// assert!(!m1.eq_with_default(&m2, &1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn eq_with_default<const M: usize>(&self, other: &Map<K, V, M>, default: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.iter().all(|(k, v)| other.get_or(k, default) == v)
+            && other
+                .iter()
+                .all(|(k, v)| self.contains_key(k) || v == default)
+    }
+
+    /// Does any value match the predicate?
+    ///
+    /// It stops at the first matching value. If the map is empty,
+    /// it returns `false`.
+    #[inline]
+    pub fn any_value<F: FnMut(&V) -> bool>(&self, mut f: F) -> bool {
+        (0..self.len).any(|i| f(&self.item_ref(i).1))
+    }
+
+    /// Do all values match the predicate?
+    ///
+    /// It stops at the first value that doesn't match. If the map is empty,
+    /// it returns `true`.
+    #[inline]
+    pub fn all_values<F: FnMut(&V) -> bool>(&self, mut f: F) -> bool {
+        (0..self.len).all(|i| f(&self.item_ref(i).1))
+    }
+
+    /// Apply the function to every pair, letting it modify the value.
+    ///
+    /// It is the same as calling `f` for each pair of [`Map::iter_mut`],
+    /// but done in a single pass over the internal array.
+    #[inline]
+    pub fn apply_to_values<F: FnMut(&K, &mut V)>(&mut self, mut f: F) {
+        for p in &mut self.pairs[..self.len] {
+            let p = unsafe { p.assume_init_mut() };
+            f(&p.0, &mut p.1);
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// Pairs are visited and dropped in the order they are stored in the map,
+    /// while the retained ones keep their relative order: they are moved
+    /// towards the beginning of the internal array in a single pass, each
+    /// of them at most once. Unlike it, [`Map::try_retain`] removes pairs
+    /// one by one, moving the last pair into the place of each removed one.
+    #[inline]
+    pub fn retain<F: Fn(&K, &V) -> bool>(&mut self, f: F) {
+        self.retain_count(|k, v| f(k, v));
+    }
+
+    /// Retains only the elements specified by the predicate, returning
+    /// the number of removed pairs.
+    ///
+    /// The predicate may change the values. Otherwise, it works exactly
+    /// like [`Map::retain`]. If the predicate panics, the pairs
+    /// not visited yet stay in the map, after the retained ones.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, i32, 4> = micromap::Map::new();
+    /// m.insert(1, 10);
+    /// m.insert(2, 20);
+    /// m.insert(3, 30);
+    /// assert_eq!(2, m.retain_count(|k, _| *k == 2));
+    /// ```
+    #[inline]
+    pub fn retain_count<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) -> usize {
+        let len = self.len;
+        self.len = 0;
+        let mut g = RetainGuard {
+            map: self,
+            visited: 0,
+            len,
+        };
+        while g.visited < len {
+            let i = g.visited;
+            let p = unsafe { g.map.pairs[i].assume_init_mut() };
+            let keep = f(&p.0, &mut p.1);
+            g.visited += 1;
+            if keep {
+                let to = g.map.len;
+                if i != to {
+                    let value = g.map.item_read(i);
+                    g.map.item_write(to, value);
+                    g.map.order_move(i, to);
+                }
+                g.map.len += 1;
+            } else {
+                g.map.item_drop(i);
+            }
+        }
+        len - g.map.len
+    }
+
+    /// Retains only the elements specified by the predicate, which may
+    /// stop the scanning at any moment.
+    ///
+    /// The predicate returns [`ControlFlow::Continue`] with `true` to keep
+    /// the pair or `false` to remove it. When it returns [`ControlFlow::Break`],
+    /// the current pair and all pairs not visited yet stay in the map,
+    /// and the value of the break is returned.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    /// let mut m: micromap::Map<u8, i32, 10> = micromap::Map::new();
+    /// m.insert(1, 42);
+    /// m.insert(2, 16);
+    /// let found = m.try_retain(|k, _v| {
+    ///     if *k == 1 {
+    ///         ControlFlow::Break(*k)
+    ///     } else {
+    ///         ControlFlow::Continue(true)
+    ///     }
+    /// });
+    /// assert_eq!(Some(1), found);
+    /// assert_eq!(2, m.len());
+    /// ```
+    #[inline]
+    pub fn try_retain<B, F: FnMut(&K, &mut V) -> ControlFlow<B, bool>>(
+        &mut self,
+        mut f: F,
+    ) -> Option<B> {
+        let mut i = 0;
+        while i < self.len {
+            let p = unsafe { self.pairs[i].assume_init_mut() };
+            match f(&p.0, &mut p.1) {
+                // do not remove -> next index
+                ControlFlow::Continue(true) => i += 1,
+                // recheck the same index
+                ControlFlow::Continue(false) => self.remove_index_drop(i),
+                ControlFlow::Break(b) => return Some(b),
+            }
+        }
+        None
+    }
+
+    /// Merge with another map into a new map of the given capacity.
+    ///
+    /// If both maps have the same key, the value from `other` wins.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are more distinct keys than `R`.
+    #[inline]
+    pub fn concat<const M: usize, const R: usize>(self, other: Map<K, V, M>) -> Map<K, V, R> {
+        let mut m: Map<K, V, R> = Map::new();
+        for (k, v) in self.into_iter().chain(other) {
+            m.insert(k, v);
+        }
+        m
+    }
+
+    /// Retains only the elements specified by the predicate, moving them
+    /// into a new map of a different capacity.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are more retained pairs than `M`.
+    #[inline]
+    pub fn retain_into<const M: usize, F: FnMut(&K, &V) -> bool>(self, mut f: F) -> Map<K, V, M> {
+        let mut m: Map<K, V, M> = Map::new();
+        for (k, v) in self {
+            if f(&k, &v) {
+                m.insert(k, v);
+            }
+        }
+        m
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    #[inline]
+    pub fn get_key_value<Q: PartialEq + ?Sized>(&self, k: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        for i in 0..self.len {
+            let p = self.item_ref(i);
+            if p.0.borrow() == k {
+                return Some((&p.0, &p.1));
+            }
+        }
+        None
+    }
+
+    /// Remove by key, keeping the order of all other pairs.
+    ///
+    /// Unlike [`Map::remove`], which moves the last pair into the emptied
+    /// slot, it shifts all pairs after the removed one slot to the left.
+    /// It is slower, but the iteration order of the remaining pairs stays
+    /// the same.
+    #[inline]
+    pub fn remove_shift<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry_shift(k).map(|p| p.1)
+    }
+
+    /// Removes a key from the map, returning the stored key and value if the
+    /// key was previously in the map, keeping the order of all other pairs.
+    ///
+    /// See [`Map::remove_shift`].
+    #[inline]
+    pub fn remove_entry_shift<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let i = self.index_of(k)?;
+        Some(self.remove_index_shift(i))
+    }
+
+    /// Removes a key from the map, returning the stored key and value if the
+    /// key was previously in the map.
+    #[inline]
+    pub fn remove_entry<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        for i in 0..self.len {
+            let p = self.item_ref(i);
+            if p.0.borrow() == k {
+                return Some(self.remove_index_read(i));
+            }
+        }
+        None
+    }
+
+    pub fn entry(&mut self, k: K) -> Entry<'_, K, V, N> {
+        for i in 0..self.len {
+            let p = self.item_ref(i);
+            if p.0 == k {
+                return Entry::Occupied(OccupiedEntry {
+                    index: i,
+                    table: self,
+                });
+            }
+        }
+        Entry::Vacant(VacantEntry {
+            key: k,
+            table: self,
+        })
+    }
+}
+
+#[cfg(feature = "insertion_order")]
+impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    /// Make an iterator over all pairs, in the order of their insertion.
+    ///
+    /// Updating the value of an existing key doesn't change its position,
+    /// while removing a key and inserting it again moves it to the end.
+    /// Each call sorts the pairs, which costs O(n log n).
+    #[inline]
+    pub fn iter_insertion_order(&self) -> impl Iterator<Item = (&K, &V)> {
+        let indices = self.insertion_indices();
+        indices.into_iter().take(self.len).map(|i| {
+            let p = self.item_ref(i);
+            (&p.0, &p.1)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
 
     #[test]
     fn insert_and_check_length() {
@@ -322,7 +1411,11 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
+    #[cfg_attr(
+        feature = "std",
+        should_panic(expected = "No more keys available in the map")
+    )]
+    #[cfg_attr(not(feature = "std"), should_panic(expected = "index out of bounds"))]
     #[cfg(debug_assertions)]
     fn cant_write_into_empty_map() {
         let mut m: Map<i32, i32, 0> = Map::new();
@@ -420,6 +1513,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::box_default)]
     fn large_map_in_heap() {
         let m: Box<Map<u64, [u64; 10], 10>> = Box::new(Map::new());
         assert_eq!(0, m.len());
@@ -445,28 +1539,612 @@ mod test {
     }
 
     #[test]
-    fn insert_many_and_remove() {
-        let mut m: Map<usize, u64, 4> = Map::new();
-        for _ in 0..2 {
-            let cap = m.capacity();
-            for i in 0..cap {
-                assert_eq!(m.insert(i, 256), None);
-                assert_eq!(m.remove(&i), Some(256));
-            }
-        }
+    fn contains_keys_mask_of_present_and_absent() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 1);
+        m.insert("three".to_string(), 3);
+        m.insert("four".to_string(), 4);
+        assert_eq!(
+            0b1101,
+            m.contains_keys_mask(["one", "two", "three", "four", "five"])
+        );
+        assert_eq!(0, m.contains_keys_mask(["two", "five"]));
+        assert_eq!(0, m.contains_keys_mask::<str, _>([]));
     }
 
     #[test]
-    fn get_key_value() {
-        let mut m: Map<String, i32, 10> = Map::new();
-        let k = "key".to_string();
-        assert_eq!(m.insert(k.clone(), 42), None);
-        assert_eq!(m.get_key_value(&k), Some((&k, &42)));
-        assert!(m.contains_key(&k));
+    fn contains_keys_mask_of_many_keys() {
+        let mut m: Map<u8, i32, 10> = Map::new();
+        m.insert(0, 0);
+        let keys = [0; 100];
+        assert_eq!(u64::MAX, m.contains_keys_mask(&keys));
     }
 
     #[test]
-    fn get_absent_key_value() {
+    fn sparse_equal_with_default() {
+        let mut m1: Map<char, i32, 10> = Map::new();
+        m1.insert('a', 0);
+        m1.insert('b', 42);
+        let mut m2: Map<char, i32, 4> = Map::new();
+        m2.insert('b', 42);
+        m2.insert('c', 0);
+        assert!(m1.eq_with_default(&m2, &0));
+        assert!(m2.eq_with_default(&m1, &0));
+        assert!(Map::<char, i32, 1>::new().eq_with_default(&Map::<char, i32, 1>::new(), &0));
+    }
+
+    #[test]
+    fn sparse_unequal_with_default() {
+        let mut m1: Map<char, i32, 10> = Map::new();
+        m1.insert('a', 0);
+        m1.insert('b', 42);
+        let mut m2: Map<char, i32, 4> = Map::new();
+        m2.insert('b', 42);
+        assert!(!m1.eq_with_default(&m2, &42));
+        m2.insert('c', 16);
+        assert!(!m1.eq_with_default(&m2, &0));
+        assert!(!m2.eq_with_default(&m1, &0));
+    }
+
+    #[test]
+    fn any_and_all_values() {
+        let m: Map<i32, i32, 10> = (0..8).map(|x| (x, x * 10)).collect();
+        assert!(!m.any_value(|v| *v > 100));
+        assert!(!m.all_values(|v| *v > 100));
+        assert!(m.any_value(|v| *v > 30));
+        assert!(!m.all_values(|v| *v > 30));
+        assert!(m.any_value(|v| *v >= 0));
+        assert!(m.all_values(|v| *v >= 0));
+    }
+
+    #[test]
+    fn any_and_all_values_in_empty_map() {
+        let m: Map<i32, i32, 10> = Map::new();
+        assert!(!m.any_value(|_| true));
+        assert!(m.all_values(|_| false));
+    }
+
+    #[test]
+    fn any_value_stops_early() {
+        let m: Map<i32, i32, 10> = (0..8).map(|x| (x, x * 10)).collect();
+        let mut seen = 0;
+        assert!(m.any_value(|_| {
+            seen += 1;
+            true
+        }));
+        assert_eq!(1, seen);
+    }
+
+    #[test]
+    fn applies_to_values() {
+        let vec: Vec<(i32, i32)> = (0..8).map(|x| (x, x * 10)).collect();
+        let mut m: Map<i32, i32, 10> = Map::from_iter(vec.clone());
+        m.apply_to_values(|k, v| *v += k);
+        let mut baseline: Map<i32, i32, 10> = Map::from_iter(vec);
+        for (k, v) in &mut baseline {
+            *v += k;
+        }
+        assert_eq!(baseline, m);
+        assert_eq!(77, m[&7]);
+    }
+
+    #[test]
+    fn as_slice_has_all_pairs() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in 0..5 {
+            m.insert(k, i32::from(k) * 10);
+        }
+        m.remove(&2);
+        assert_eq!(m.len(), m.as_slice().len());
+        for (k, v) in &m {
+            assert!(m.as_slice().contains(&(*k, *v)));
+        }
+        assert!(!m.as_slice().iter().any(|p| p.0 == 2));
+    }
+
+    #[test]
+    fn remove_shift_keeps_order() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in 1..=5 {
+            m.insert(k, i32::from(k));
+        }
+        assert_eq!(Some(3), m.remove_shift(&3));
+        assert_eq!(None, m.remove_shift(&3));
+        let keys: Vec<u8> = m.keys().copied().collect();
+        assert_eq!(vec![1, 2, 4, 5], keys);
+        assert_eq!(Some((5, 5)), m.remove_entry_shift(&5));
+        assert_eq!(Some((1, 1)), m.remove_entry_shift(&1));
+        let keys: Vec<u8> = m.keys().copied().collect();
+        assert_eq!(vec![2, 4], keys);
+    }
+
+    #[test]
+    #[cfg(feature = "insertion_order")]
+    fn remove_shift_keeps_insertion_order() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in [5, 3, 8, 1, 9] {
+            m.insert(k, i32::from(k));
+        }
+        m.remove(&3);
+        assert_eq!(Some(8), m.remove_shift(&8));
+        m.insert(2, 2);
+        assert_eq!(
+            vec![5, 1, 9, 2],
+            m.iter_insertion_order()
+                .map(|(k, _)| *k)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn remove_shift_drops_nothing_extra() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<u8, Rc<()>, 8> = Map::new();
+        for k in 0..4 {
+            m.insert(k, Rc::clone(&v));
+        }
+        let removed = m.remove_shift(&1);
+        assert_eq!(5, Rc::strong_count(&v));
+        drop(removed);
+        assert_eq!(4, Rc::strong_count(&v));
+        drop(m);
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn rotates_pairs() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in 1..=5 {
+            m.insert(k, i32::from(k));
+        }
+        m.rotate_left(2);
+        let keys: Vec<u8> = m.keys().copied().collect();
+        assert_eq!(vec![3, 4, 5, 1, 2], keys);
+        m.rotate_right(3);
+        let keys: Vec<u8> = m.keys().copied().collect();
+        assert_eq!(vec![5, 1, 2, 3, 4], keys);
+        m.rotate_left(5);
+        assert_eq!(Some((&5, &5)), m.iter().next());
+        assert_eq!(5, m.len());
+        for k in 1..=5 {
+            assert_eq!(i32::from(k), m[&k]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "insertion_order")]
+    fn rotates_without_changing_insertion_order() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in [5, 3, 8, 1, 9] {
+            m.insert(k, i32::from(k));
+        }
+        m.rotate_left(2);
+        m.rotate_right(4);
+        m.insert(2, 2);
+        assert_eq!(
+            vec![5, 3, 8, 1, 9, 2],
+            m.iter_insertion_order()
+                .map(|(k, _)| *k)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "mid <= self.len()")]
+    fn rotates_beyond_length() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        m.insert(1, 1);
+        m.rotate_left(2);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn shrinks_to_fit_without_changes() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        m.insert(1, 42);
+        m.shrink_to_fit();
+        assert_eq!(8, m.capacity());
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn makes_sorted_vec() {
+        let mut m: Map<u8, &str, 8> = Map::new();
+        for (k, v) in [(3, "three"), (1, "one"), (4, "four"), (2, "two")] {
+            m.insert(k, v);
+        }
+        assert_eq!(
+            vec![(1, "one"), (2, "two"), (3, "three"), (4, "four")],
+            m.to_sorted_vec()
+        );
+        assert_eq!(4, m.len());
+        assert!(Map::<u8, u8, 4>::new().to_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn retains_and_counts_removed() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in 0..6 {
+            m.insert(k, i32::from(k));
+        }
+        let removed = m.retain_count(|k, v| {
+            *v *= 10;
+            k % 3 != 0
+        });
+        assert_eq!(2, removed);
+        assert_eq!(
+            vec![(1, 10), (2, 20), (4, 40), (5, 50)],
+            m.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>()
+        );
+        assert_eq!(0, m.retain_count(|_, _| true));
+        assert_eq!(4, m.retain_count(|_, _| false));
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn pops_all_pairs() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in 0..8 {
+            m.insert(k, i32::from(k) * 10);
+        }
+        m.remove(&3);
+        let mut seen = vec![];
+        while let Some(last) = m.peek_last().map(|(k, v)| (*k, *v)) {
+            let (k, v) = m.pop().unwrap();
+            assert_eq!(last, (k, v));
+            assert_eq!(i32::from(k) * 10, v);
+            seen.push(k);
+        }
+        assert!(m.pop().is_none());
+        seen.sort_unstable();
+        assert_eq!(vec![0, 1, 2, 4, 5, 6, 7], seen);
+        assert_eq!(0, m.len());
+        assert!(m.peek_last().is_none());
+    }
+
+    #[test]
+    fn contains_value() {
+        let mut m: Map<u8, String, 4> = Map::new();
+        m.insert(1, "red".to_string());
+        m.insert(2, "green".to_string());
+        m.insert(3, "blue".to_string());
+        assert!(m.contains_value("green"));
+        assert!(m.contains_value(&"blue".to_string()));
+        assert!(!m.contains_value("yellow"));
+        m.remove(&2);
+        assert!(!m.contains_value("green"));
+    }
+
+    #[test]
+    fn maps_values() {
+        let mut m: Map<String, u32, 4> = Map::new();
+        m.insert("one".to_string(), 1);
+        m.insert("two".to_string(), 2);
+        m.insert("three".to_string(), 3);
+        let keys: Vec<String> = m.keys().cloned().collect();
+        let m: Map<String, String, 4> = m.map_values(|k, v| format!("{k}={v}"));
+        assert_eq!(3, m.len());
+        assert_eq!(keys, m.keys().cloned().collect::<Vec<_>>());
+        assert_eq!("two=2", m["two"]);
+    }
+
+    #[test]
+    fn maps_values_dropping_everything_once() {
+        use std::rc::Rc;
+        let k = Rc::new(());
+        let mut m: Map<u8, Rc<()>, 4> = Map::new();
+        m.insert(1, Rc::clone(&k));
+        m.insert(2, Rc::clone(&k));
+        let m = m.map_values(|_, v| Rc::strong_count(&v));
+        assert_eq!(1, Rc::strong_count(&k));
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn drops_all_pairs_when_map_values_panics() {
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+        #[derive(PartialEq)]
+        struct Key(u8, Rc<Cell<usize>>);
+        impl Drop for Key {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+            }
+        }
+        let drops = Rc::new(Cell::new(0));
+        let mut m: Map<Key, u8, 8> = Map::new();
+        for i in 0..4 {
+            m.insert(Key(i, Rc::clone(&drops)), i);
+        }
+        let r = catch_unwind(AssertUnwindSafe(move || {
+            m.map_values(|k, v| {
+                assert!(k.0 != 2, "Can't map the third value");
+                u32::from(v)
+            })
+        }));
+        assert!(r.is_err());
+        assert_eq!(4, drops.get());
+    }
+
+    #[test]
+    fn gets_by_custom_comparison() {
+        let mut m: Map<String, i32, 4> = Map::new();
+        m.insert("Alpha".to_string(), 1);
+        m.insert("beta".to_string(), 2);
+        let ci = |k: &String, n: &str| k.to_lowercase() == n.to_lowercase();
+        assert_eq!(Some(&1), m.get_by_cmp("ALPHA", ci));
+        assert_eq!(Some(&2), m.get_by_cmp("Beta", ci));
+        assert_eq!(None, m.get_by_cmp("gamma", ci));
+        assert_eq!(None, m.get("ALPHA"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn splits_into_columns() {
+        let mut m: Map<u8, String, 8> = Map::new();
+        for k in [3, 1, 4, 2] {
+            m.insert(k, format!("v{k}"));
+        }
+        let expected = m.to_sorted_vec();
+        let (keys, values) = m.into_columns();
+        assert_eq!(keys.len(), values.len());
+        let mut pairs: Vec<(u8, String)> = keys.into_iter().zip(values).collect();
+        pairs.sort_unstable();
+        assert_eq!(expected, pairs);
+    }
+
+    #[test]
+    fn concats_disjoint_maps() {
+        let a: Map<u8, i32, 2> = [(1, 1), (2, 2)].into_iter().collect();
+        let b: Map<u8, i32, 3> = [(3, 3), (4, 4), (5, 5)].into_iter().collect();
+        let m: Map<u8, i32, 5> = a.concat(b);
+        assert_eq!(5, m.len());
+        for k in 1..=5 {
+            assert_eq!(i32::from(k), m[&k]);
+        }
+    }
+
+    #[test]
+    fn concats_with_collision() {
+        let a: Map<u8, i32, 2> = [(1, 1), (2, 2)].into_iter().collect();
+        let b: Map<u8, i32, 2> = [(2, 16), (3, 3)].into_iter().collect();
+        let m: Map<u8, i32, 3> = a.concat(b);
+        assert_eq!(3, m.len());
+        assert_eq!(16, m[&2]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        feature = "std",
+        should_panic(expected = "No more keys available in the map")
+    )]
+    #[cfg_attr(not(feature = "std"), should_panic(expected = "index out of bounds"))]
+    #[cfg(debug_assertions)]
+    fn concats_beyond_capacity() {
+        let a: Map<u8, i32, 2> = [(1, 1), (2, 2)].into_iter().collect();
+        let b: Map<u8, i32, 2> = [(3, 3), (4, 4)].into_iter().collect();
+        let _m: Map<u8, i32, 3> = a.concat(b);
+    }
+
+    #[test]
+    fn retain_into_smaller_map() {
+        let m: Map<i32, i32, 8> = (0..8).map(|x| (x, x * 10)).collect();
+        let r: Map<i32, i32, 4> = m.retain_into(|&k, _| k % 2 == 0);
+        assert_eq!(4, r.len());
+        assert_eq!(4, r.capacity());
+        for k in [0, 2, 4, 6] {
+            assert_eq!(k * 10, r[&k]);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        feature = "std",
+        should_panic(expected = "No more keys available in the map")
+    )]
+    #[cfg_attr(not(feature = "std"), should_panic(expected = "index out of bounds"))]
+    #[cfg(debug_assertions)]
+    fn retain_into_too_small_map() {
+        let m: Map<i32, i32, 8> = (0..8).map(|x| (x, x * 10)).collect();
+        let _r: Map<i32, i32, 4> = m.retain_into(|&k, _| k > 2);
+    }
+
+    #[test]
+    fn drops_pairs_in_stored_order() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        #[derive(PartialEq)]
+        struct Logged(u8, Rc<RefCell<Vec<u8>>>);
+        impl Drop for Logged {
+            fn drop(&mut self) {
+                self.1.borrow_mut().push(self.0);
+            }
+        }
+        let log = Rc::new(RefCell::new(vec![]));
+        let make =
+            || -> Map<u8, Logged, 8> { (0..5).map(|k| (k, Logged(k, Rc::clone(&log)))).collect() };
+        make().clear();
+        assert_eq!(vec![0, 1, 2, 3, 4], log.take());
+        let mut m = make();
+        let mut drain = m.drain();
+        let first = drain.next();
+        drop(drain);
+        assert_eq!(vec![1, 2, 3, 4], log.take());
+        drop(first);
+        assert_eq!(vec![0], log.take());
+        let mut m = make();
+        m.retain(|k, _| k % 2 == 0);
+        assert_eq!(vec![1, 3], log.take());
+        assert_eq!(vec![0, 2, 4], m.keys().copied().collect::<Vec<_>>());
+        drop(m);
+        assert_eq!(vec![0, 2, 4], log.take());
+        let mut iter = make().into_iter();
+        let last = iter.next();
+        drop(iter);
+        assert_eq!(vec![0, 1, 2, 3], log.take());
+        drop(last);
+        assert_eq!(vec![4], log.take());
+    }
+
+    #[test]
+    fn keeps_unvisited_pairs_when_retain_panics() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<u8, Rc<()>, 8> = (0..4).map(|k| (k, Rc::clone(&v))).collect();
+        let r = catch_unwind(AssertUnwindSafe(|| {
+            m.retain(|k, _| {
+                assert!(*k != 1, "Can't check the second key");
+                *k != 0
+            });
+        }));
+        assert!(r.is_err());
+        assert_eq!(vec![1, 2, 3], m.keys().copied().collect::<Vec<_>>());
+        assert_eq!(4, Rc::strong_count(&v));
+        drop(m);
+        assert_eq!(1, Rc::strong_count(&v));
+    }
+
+    #[test]
+    fn retain_keeps_order() {
+        let mut m: Map<i32, i32, 10> = (0..10).map(|x| (x, x * 10)).collect();
+        let mut visited = vec![];
+        let removed = m.retain_count(|&k, _| {
+            visited.push(k);
+            k % 3 != 0
+        });
+        assert_eq!(4, removed);
+        assert_eq!((0..10).collect::<Vec<_>>(), visited);
+        assert_eq!(
+            vec![1, 2, 4, 5, 7, 8],
+            m.keys().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(80, m[&8]);
+    }
+
+    #[test]
+    #[cfg(feature = "insertion_order")]
+    fn retain_keeps_insertion_order() {
+        let mut m: Map<i32, i32, 10> = (0..10).map(|x| (x, x * 10)).collect();
+        m.move_key_to_front(&9);
+        m.retain(|&k, _| k % 2 == 1);
+        let keys: Vec<i32> = m.iter_insertion_order().map(|(k, _)| *k).collect();
+        assert_eq!(vec![1, 3, 5, 7, 9], keys);
+    }
+
+    #[test]
+    fn try_retain_breaks_after_first_removal() {
+        let vec: Vec<(i32, i32)> = (0..8).map(|x| (x, x * 10)).collect();
+        let mut m: Map<i32, i32, 10> = Map::from_iter(vec);
+        let mut removed = None;
+        let found = m.try_retain(|&k, v| {
+            if removed.is_some() {
+                return ControlFlow::Break(k);
+            }
+            if k % 3 == 2 {
+                removed = Some(k);
+                return ControlFlow::Continue(false);
+            }
+            *v += 1;
+            ControlFlow::Continue(true)
+        });
+        assert_eq!(Some(2), removed);
+        assert_eq!(Some(7), found);
+        assert_eq!(7, m.len());
+        assert!(!m.contains_key(&2));
+        assert_eq!(1, m[&0]);
+        assert_eq!(11, m[&1]);
+        for k in 3..8 {
+            assert_eq!(k * 10, m[&k]);
+        }
+    }
+
+    #[test]
+    fn try_retain_runs_to_completion() {
+        let vec: Vec<(i32, i32)> = (0..8).map(|x| (x, x * 10)).collect();
+        let mut m: Map<i32, i32, 10> = Map::from_iter(vec);
+        let found: Option<()> = m.try_retain(|&k, _| ControlFlow::Continue(k < 6));
+        assert_eq!(None, found);
+        assert_eq!(6, m.len());
+    }
+
+    #[test]
+    fn finds_entries_by_derived_key() {
+        let mut m: Map<i32, i32, 10> = Map::new();
+        for (k, v) in [(1, 16), (2, 42), (3, 8), (4, 23), (5, 30)] {
+            m.insert(k, v);
+        }
+        assert_eq!(Some((&3, &8)), m.entry_min_by_key(|_, v| *v));
+        assert_eq!(Some((&2, &42)), m.entry_max_by_key(|k, v| k + v));
+        assert_eq!(Some((&5, &30)), m.entry_max_by_key(|k, v| k * 10 + v));
+        m.clear();
+        assert_eq!(None, m.entry_min_by_key(|_, v| *v));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn joins_keys() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        for k in ["a", "b", "c"] {
+            m.insert(k.to_string(), 42);
+        }
+        let joined = m.keys_joined(", ");
+        let mut parts: Vec<&str> = joined.split(", ").collect();
+        parts.sort_unstable();
+        assert_eq!(vec!["a", "b", "c"], parts);
+        assert_eq!("", Map::<u8, u8, 1>::new().keys_joined(", "));
+    }
+
+    #[test]
+    fn top_two_by_value() {
+        let mut m: Map<char, i32, 10> = Map::new();
+        for (k, v) in [('a', 16), ('b', 42), ('c', 8), ('d', 23), ('e', 4)] {
+            m.insert(k, v);
+        }
+        assert_eq!(
+            [Some((&'b', &42)), Some((&'d', &23))],
+            m.top_k_by_value::<2>()
+        );
+        assert_eq!(5, m.len());
+    }
+
+    #[test]
+    fn top_k_by_value_more_than_len() {
+        let mut m: Map<char, i32, 10> = Map::new();
+        m.insert('a', 16);
+        m.insert('b', 42);
+        assert_eq!(
+            [Some((&'b', &42)), Some((&'a', &16)), None],
+            m.top_k_by_value::<3>()
+        );
+    }
+
+    #[test]
+    fn insert_many_and_remove() {
+        let mut m: Map<usize, u64, 4> = Map::new();
+        for _ in 0..2 {
+            let cap = m.capacity();
+            for i in 0..cap {
+                assert_eq!(m.insert(i, 256), None);
+                assert_eq!(m.remove(&i), Some(256));
+            }
+        }
+    }
+
+    #[test]
+    fn get_key_value() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        let k = "key".to_string();
+        assert_eq!(m.insert(k.clone(), 42), None);
+        assert_eq!(m.get_key_value(&k), Some((&k, &42)));
+        assert!(m.contains_key(&k));
+    }
+
+    #[test]
+    fn get_absent_key_value() {
         let mut m: Map<String, i32, 10> = Map::new();
         assert_eq!(m.insert("one".to_string(), 42), None);
         assert_eq!(m.get_key_value("two"), None);
@@ -528,4 +2206,512 @@ mod test {
         assert_eq!(1, m.len());
         assert_eq!(3, m[&2]);
     }
+
+    #[test]
+    fn get_or_present_and_absent() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 42);
+        assert_eq!(42, *m.get_or("one", &7));
+        assert_eq!(7, *m.get_or("two", &7));
+    }
+
+    #[test]
+    fn finds_key_by_value() {
+        let mut m: Map<u8, String, 10> = Map::new();
+        m.insert(1, "one".to_string());
+        m.insert(2, "two".to_string());
+        m.insert(3, "one".to_string());
+        assert_eq!(Some(&1), m.find_key_by_value("one"));
+        assert_eq!(Some(&2), m.find_key_by_value(&"two".to_string()));
+        assert_eq!(None, m.find_key_by_value("three"));
+    }
+
+    #[test]
+    fn updates_present_values_only() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 1);
+        m.insert("two".to_string(), 2);
+        let updated = m.update_values([("one", 16), ("three", 42), ("two", 8), ("one", 23)]);
+        assert_eq!(3, updated);
+        assert_eq!(23, m["one"]);
+        assert_eq!(8, m["two"]);
+        assert!(!m.contains_key("three"));
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn get_or_default_present_and_absent() {
+        let mut m: Map<String, Vec<u8>, 10> = Map::new();
+        m.insert("one".to_string(), vec![1, 2]);
+        assert_eq!(vec![1, 2], m.get_or_default("one"));
+        assert!(m.get_or_default("two").is_empty());
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn get_or_insert_default_present() {
+        let mut m: Map<String, Vec<u8>, 10> = Map::new();
+        m.insert("one".to_string(), vec![1, 2]);
+        assert_eq!(&vec![1, 2], m.get_or_insert_default("one".to_string()));
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn get_or_insert_default_absent() {
+        let mut m: Map<String, Vec<u8>, 10> = Map::new();
+        let v = m.get_or_insert_default("one".to_string());
+        assert!(v.is_empty());
+        v.push(42);
+        assert_eq!(vec![42], m["one"]);
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn insert_if_absent_inserts() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        let v = m.insert_if_absent("one".to_string(), 42).unwrap();
+        *v += 1;
+        assert_eq!(43, m["one"]);
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn insert_if_absent_rejects() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 42);
+        assert_eq!(
+            Err(("one".to_string(), 16)),
+            m.insert_if_absent("one".to_string(), 16)
+        );
+        assert_eq!(42, m["one"]);
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn reads_pairs_through_slice() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        for (k, v) in [("one", 1), ("two", 2), ("three", 3)] {
+            m.insert(k.to_string(), v);
+        }
+        m.remove("one");
+        let pairs = m.pairs();
+        assert_eq!(m.pairs_len(), pairs.len());
+        assert_eq!(m.len(), pairs.len());
+        for ((k, v), p) in m.iter().zip(pairs) {
+            assert_eq!((k, v), (&p.0, &p.1));
+        }
+        assert!(Map::<u8, u8, 0>::new().pairs().is_empty());
+    }
+
+    #[test]
+    fn stack_size_covers_all_pairs() {
+        type M = Map<u64, u32, 8>;
+        assert!(M::stack_size() >= 8 * size_of::<(u64, u32)>() + size_of::<usize>());
+        assert_eq!(M::STACK_BYTES, M::stack_size());
+        assert_eq!(size_of::<M>(), M::stack_size());
+    }
+
+    #[test]
+    fn moves_keys_to_front_by_recency() {
+        let mut m: Map<char, i32, 4> = Map::new();
+        for (k, v) in [('a', 1), ('b', 2), ('c', 3), ('d', 4)] {
+            m.insert(k, v);
+        }
+        for k in ['c', 'a', 'd', 'a'] {
+            assert!(m.move_key_to_front(&k));
+        }
+        assert!(!m.move_key_to_front(&'e'));
+        assert_eq!(
+            vec!['a', 'd', 'c', 'b'],
+            m.keys().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(3, m[&'c']);
+        assert_eq!(4, m.len());
+    }
+
+    #[test]
+    #[cfg(feature = "insertion_order")]
+    fn moves_key_to_front_keeping_insertion_order() {
+        let mut m: Map<char, i32, 4> = Map::new();
+        for (k, v) in [('a', 1), ('b', 2), ('c', 3)] {
+            m.insert(k, v);
+        }
+        m.move_key_to_front(&'c');
+        let keys: Vec<char> = m.iter_insertion_order().map(|(k, _)| *k).collect();
+        assert_eq!(vec!['a', 'b', 'c'], keys);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut m: Map<char, i32, 3> = Map::new();
+        for (k, v) in [('a', 1), ('b', 2), ('c', 3)] {
+            m.insert(k, v);
+            m.move_key_to_front(&k);
+        }
+        m.move_key_to_front(&'a');
+        m.move_key_to_front(&'c');
+        assert_eq!(Some(('b', 2)), m.evict_back());
+        m.insert('d', 4);
+        m.move_key_to_front(&'d');
+        assert_eq!(Some(('a', 1)), m.evict_back());
+        assert_eq!(Some(('c', 3)), m.evict_back());
+        assert_eq!(Some(('d', 4)), m.evict_back());
+        assert_eq!(None, m.evict_back());
+    }
+
+    #[test]
+    fn interns_strings() {
+        let mut m: Map<String, usize, 10> = Map::new();
+        let mut ids = vec![];
+        for s in ["foo", "bar", "foo", "baz", "bar"] {
+            let next = m.len();
+            let (i, id) = m.intern(s.to_string(), || next);
+            assert_eq!(i, *id);
+            ids.push(*id);
+        }
+        assert_eq!(vec![0, 1, 0, 2, 1], ids);
+        assert_eq!(3, m.len());
+        assert_eq!(2, m["baz"]);
+    }
+
+    #[test]
+    fn try_insert_into_vacant_with_space() {
+        let mut m: Map<String, i32, 2> = Map::new();
+        let v = m.try_insert("one".to_string(), 42).unwrap();
+        *v += 1;
+        assert_eq!(43, m["one"]);
+    }
+
+    #[test]
+    fn try_insert_into_occupied() {
+        let mut m: Map<String, i32, 2> = Map::new();
+        m.insert("one".to_string(), 42);
+        let Err(TryInsertError::Occupied(mut e)) = m.try_insert("one".to_string(), 16) else {
+            panic!("The key must be occupied");
+        };
+        assert_eq!("one", e.entry.key());
+        assert_eq!(16, e.value);
+        *e.entry.get_mut() += 1;
+        assert_eq!(43, m["one"]);
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn try_insert_into_full() {
+        let mut m: Map<String, i32, 1> = Map::new();
+        m.insert("one".to_string(), 42);
+        let Err(TryInsertError::Full(e)) = m.try_insert("two".to_string(), 16) else {
+            panic!("The map must be full");
+        };
+        assert_eq!(
+            CapacityError {
+                key: "two".to_string(),
+                value: 16
+            },
+            e
+        );
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn insert_full_returns_index_and_old_value() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        for (k, v) in [("one", 1), ("two", 2), ("three", 3)] {
+            let (i, old) = m.insert_full(k.to_string(), v);
+            assert_eq!(None, old);
+            assert_eq!(m.keys().position(|x| x == k), Some(i));
+        }
+        m.remove("one");
+        let (i, old) = m.insert_full("two".to_string(), 16);
+        assert_eq!(Some(2), old);
+        assert_eq!(m.keys().position(|x| x == "two"), Some(i));
+        let mut other = m.clone();
+        assert_eq!(
+            other.insert("three".to_string(), 8),
+            m.insert_full("three".to_string(), 8).1
+        );
+    }
+
+    #[test]
+    fn insert_checked_in_all_cases() {
+        let mut m: Map<u8, i32, 2> = Map::new();
+        assert_eq!(Ok(None), m.insert_checked(1, 42));
+        assert_eq!(Ok(None), m.insert_checked(2, 16));
+        assert_eq!(Ok(Some(42)), m.insert_checked(1, 8));
+        assert_eq!(Err((3, 4)), m.insert_checked(3, 4));
+        assert_eq!(Ok(Some(16)), m.insert_checked(2, 23));
+        assert_eq!(2, m.len());
+        assert_eq!(8, m[&1]);
+        assert_eq!(23, m[&2]);
+    }
+
+    #[test]
+    fn insert_checked_without_capacity() {
+        let mut m: Map<u8, i32, 0> = Map::new();
+        assert_eq!(Err((1, 42)), m.insert_checked(1, 42));
+    }
+
+    #[test]
+    fn insert_with_mode_below_capacity() {
+        for mode in [
+            InsertMode::Panic,
+            InsertMode::Saturate,
+            InsertMode::Overwrite,
+        ] {
+            let mut m: Map<u8, i32, 3> = Map::new();
+            assert_eq!(Ok(Inserted::Added), m.insert_with_mode(1, 42, mode));
+            assert_eq!(Ok(Inserted::Replaced(42)), m.insert_with_mode(1, 16, mode));
+            assert_eq!(Ok(Inserted::Added), m.insert_with_mode(2, 8, mode));
+            assert_eq!(16, m[&1]);
+            assert_eq!(2, m.len());
+        }
+    }
+
+    #[test]
+    fn insert_with_mode_updates_at_capacity() {
+        for mode in [
+            InsertMode::Panic,
+            InsertMode::Saturate,
+            InsertMode::Overwrite,
+        ] {
+            let mut m: Map<u8, i32, 2> = Map::new();
+            m.insert(1, 42);
+            m.insert(2, 16);
+            assert_eq!(Ok(Inserted::Replaced(42)), m.insert_with_mode(1, 8, mode));
+            assert_eq!(8, m[&1]);
+            assert_eq!(2, m.len());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "No more keys available in the map")]
+    #[cfg(all(feature = "std", debug_assertions))]
+    fn insert_with_mode_panics_at_capacity() {
+        let mut m: Map<u8, i32, 1> = Map::new();
+        m.insert(1, 42);
+        let _ = m.insert_with_mode(2, 16, InsertMode::Panic);
+    }
+
+    #[test]
+    fn insert_with_mode_saturates_at_capacity() {
+        let mut m: Map<u8, i32, 2> = Map::new();
+        m.insert(1, 42);
+        m.insert(2, 16);
+        assert_eq!(Err((3, 8)), m.insert_with_mode(3, 8, InsertMode::Saturate));
+        assert!(!m.contains_key(&3));
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn insert_with_mode_overwrites_at_capacity() {
+        let mut m: Map<u8, i32, 2> = Map::new();
+        m.insert(1, 42);
+        m.insert(2, 16);
+        let last = m.iter().next_back().map(|(k, v)| (*k, *v));
+        assert_eq!(Some((2, 16)), last);
+        assert_eq!(
+            Ok(Inserted::Evicted(2, 16)),
+            m.insert_with_mode(3, 8, InsertMode::Overwrite)
+        );
+        assert!(!m.contains_key(&2));
+        assert_eq!(8, m[&3]);
+        assert_eq!(42, m[&1]);
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn insert_with_mode_overwrites_nothing_without_capacity() {
+        let mut m: Map<u8, i32, 0> = Map::new();
+        assert_eq!(
+            Err((1, 42)),
+            m.insert_with_mode(1, 42, InsertMode::Overwrite)
+        );
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "insertion_order")]
+    fn iterates_in_insertion_order() {
+        let mut m: Map<i32, i32, 10> = Map::new();
+        for k in [5, 3, 8, 1, 9] {
+            m.insert(k, k * 10);
+        }
+        m.insert(3, 33);
+        assert_eq!(
+            vec![5, 3, 8, 1, 9],
+            m.iter_insertion_order()
+                .map(|(k, _)| *k)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some((&3, &33)), m.iter_insertion_order().nth(1));
+    }
+
+    #[test]
+    #[cfg(feature = "insertion_order")]
+    fn insertion_order_survives_removals() {
+        let mut m: Map<i32, i32, 10> = Map::new();
+        for k in [5, 3, 8, 1, 9] {
+            m.insert(k, k);
+        }
+        m.remove(&3);
+        m.retain(|k, _| *k != 8);
+        m.insert(3, 3);
+        *m.entry(1).or_insert(0) += 10;
+        assert_eq!(
+            vec![5, 1, 9, 3],
+            m.iter_insertion_order()
+                .map(|(k, _)| *k)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![5, 1, 9, 3],
+            m.clone()
+                .iter_insertion_order()
+                .map(|(k, _)| *k)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "insertion_order")]
+    fn insertion_order_renumbers_on_overflow() {
+        let mut m: Map<i32, i32, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        m.next = u32::MAX;
+        m.insert(3, 3);
+        assert_eq!(3, m.next);
+        assert_eq!(
+            vec![1, 2, 3],
+            m.iter_insertion_order()
+                .map(|(k, _)| *k)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "mtf")]
+    fn get_mut_moves_found_pair_to_front() {
+        let mut m: Map<u8, i32, 32> = (0..32).map(|k| (k, i32::from(k))).collect();
+        *m.get_mut(&30).unwrap() += 100;
+        assert_eq!(Some((&30, &130)), m.iter().next());
+        assert_eq!(Some((&0, &0)), m.iter().nth(30));
+        assert!(m.get_mut(&42).is_none());
+        assert_eq!(32, m.len());
+    }
+
+    #[test]
+    #[cfg(feature = "mtf")]
+    fn get_mut_moves_indices_of_insert_full_and_intern() {
+        let mut m: Map<char, i32, 4> = Map::new();
+        m.insert('a', 1);
+        assert_eq!((1, None), m.insert_full('b', 2));
+        assert_eq!(1, m.intern('b', || 42).0);
+        let _ = m.get_mut(&'b');
+        assert_eq!(0, m.intern('b', || 42).0);
+        assert_eq!((1, Some(1)), m.insert_full('a', 10));
+    }
+
+    #[test]
+    #[cfg(feature = "mtf")]
+    fn get_mut_breaks_recency_order() {
+        let mut m: Map<char, i32, 4> = Map::new();
+        m.insert('a', 1);
+        m.insert('b', 2);
+        m.insert('c', 3);
+        m.move_key_to_front(&'c');
+        let _ = m.get_mut(&'b');
+        assert_eq!(Some(('c', 3)), m.evict_back());
+        assert_eq!(Some(('a', 1)), m.evict_back());
+    }
+
+    #[test]
+    #[cfg(not(feature = "mtf"))]
+    fn get_mut_keeps_pairs_in_place() {
+        let mut m: Map<u8, i32, 32> = (0..32).map(|k| (k, i32::from(k))).collect();
+        *m.get_mut(&30).unwrap() += 100;
+        assert_eq!(Some((&30, &130)), m.iter().nth(30));
+    }
+
+    #[test]
+    fn get_disjoint_mut_present_and_absent() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 1);
+        m.insert("two".to_string(), 2);
+        m.insert("three".to_string(), 3);
+        let [a, b, c] = m.get_disjoint_mut(["three", "four", "one"]);
+        *a.unwrap() += 30;
+        assert!(b.is_none());
+        *c.unwrap() += 10;
+        assert_eq!(33, m["three"]);
+        assert_eq!(11, m["one"]);
+        assert_eq!(2, m["two"]);
+    }
+
+    #[test]
+    fn get_disjoint_mut_absent_duplicates() {
+        let mut m: Map<u8, u8, 4> = Map::new();
+        m.insert(1, 1);
+        let [a, b, c] = m.get_disjoint_mut([&7, &1, &7]);
+        assert!(a.is_none());
+        assert_eq!(Some(&mut 1), b);
+        assert!(c.is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Overlapping keys")]
+    fn get_disjoint_mut_overlapping_keys() {
+        let mut m: Map<u8, u8, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        let _ = m.get_disjoint_mut([&1, &2, &1]);
+    }
+
+    #[test]
+    fn get_disjoint_mut_keys_more_than_capacity() {
+        let mut m: Map<u8, u8, 2> = Map::new();
+        m.insert(1, 10);
+        m.insert(2, 20);
+        assert_eq!(
+            [None, Some(&mut 20), None, Some(&mut 10)],
+            m.get_disjoint_mut([&3, &2, &4, &1])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Overlapping keys")]
+    fn get_disjoint_mut_more_hits_than_pairs() {
+        let mut m: Map<u8, u8, 2> = Map::new();
+        m.insert(1, 10);
+        m.insert(2, 20);
+        let _ = m.get_disjoint_mut([&1, &2, &2, &1]);
+    }
+
+    #[test]
+    fn get_disjoint_mut_present_all_present() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 1);
+        m.insert("two".to_string(), 2);
+        let [a, b] = m.get_disjoint_mut_present(["two", "one"]).unwrap();
+        std::mem::swap(a, b);
+        assert_eq!(2, m["one"]);
+        assert_eq!(1, m["two"]);
+    }
+
+    #[test]
+    fn get_disjoint_mut_present_any_missing() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 1);
+        assert!(m.get_disjoint_mut_present(["one", "two"]).is_none());
+        assert!(m.get_disjoint_mut_present::<str, 0>([]).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Overlapping keys")]
+    fn get_disjoint_mut_present_overlapping_keys() {
+        let mut m: Map<String, i32, 10> = Map::new();
+        m.insert("one".to_string(), 1);
+        m.insert("two".to_string(), 2);
+        let _r = m.get_disjoint_mut_present(["one", "two", "one"]);
+    }
 }
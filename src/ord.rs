@@ -0,0 +1,88 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use core::cmp::Ordering;
+
+impl<K: Ord, V: Ord, const N: usize> PartialOrd for Map<K, V, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, V: Ord, const N: usize> Ord for Map<K, V, N> {
+    /// Two maps can be ordered.
+    ///
+    /// They are compared as sequences of pairs, sorted by keys, that's why
+    /// the result doesn't depend on the order of insertion. Since the map
+    /// is not sorted, it takes O(n log n).
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut m1: micromap::Map<u8, i32, 10> = micromap::Map::new();
+    /// let mut m2: micromap::Map<u8, i32, 10> = micromap::Map::new();
+    /// m1.insert(1, 42);
+    /// m2.insert(1, 16);
+    /// assert!(m1 > m2);
+    /// ```
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mine = self.sorted_indices();
+        let theirs = other.sorted_indices();
+        mine[..self.len]
+            .iter()
+            .map(|i| self.item_ref(*i))
+            .cmp(theirs[..other.len].iter().map(|i| other.item_ref(*i)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn compares_maps_built_in_different_order() {
+        let mut m1: Map<String, i32, 10> = Map::new();
+        m1.insert("one".to_string(), 1);
+        m1.insert("two".to_string(), 2);
+        let mut m2: Map<String, i32, 10> = Map::new();
+        m2.insert("two".to_string(), 2);
+        m2.insert("one".to_string(), 1);
+        assert_eq!(Ordering::Equal, m1.cmp(&m2));
+        assert_eq!(Some(Ordering::Equal), m1.partial_cmp(&m2));
+    }
+
+    #[test]
+    fn orders_maps_by_sorted_pairs() {
+        let m1: Map<u8, i32, 4> = [(1, 1), (3, 3)].into_iter().collect();
+        let m2: Map<u8, i32, 4> = [(2, 2), (1, 1)].into_iter().collect();
+        let mut m3: Map<u8, i32, 4> = Map::new();
+        m3.insert(1, 1);
+        assert!(m1 > m2);
+        assert!(m3 < m1);
+        assert!(Map::<u8, i32, 4>::new() < m3);
+        let mut sorted = vec![m1.clone(), m2.clone(), m3.clone()];
+        sorted.sort();
+        assert_eq!(vec![m3, m2, m1], sorted);
+    }
+}
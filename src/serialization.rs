@@ -21,7 +21,7 @@
 use crate::Map;
 use core::fmt::Formatter;
 use core::marker::PhantomData;
-use serde::de::{MapAccess, Visitor};
+use serde::de::{Error, MapAccess, Visitor};
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -31,7 +31,7 @@ impl<K: PartialEq + Serialize, V: Serialize, const N: usize> Serialize for Map<K
         S: Serializer,
     {
         let mut map = serializer.serialize_map(Some(self.len()))?;
-        for (a, v) in self.iter() {
+        for (a, v) in self {
             map.serialize_entry(a, v)?;
         }
         map.end()
@@ -55,7 +55,11 @@ impl<'de, K: PartialEq + Deserialize<'de>, V: Deserialize<'de>, const N: usize>
     {
         let mut m: Self::Value = Map::new();
         while let Some((key, value)) = access.next_entry()? {
-            m.insert(key, value);
+            if m.insert_checked(key, value).is_err() {
+                return Err(M::Error::custom(format_args!(
+                    "more than {N} distinct keys in a Map"
+                )));
+            }
         }
         Ok(m)
     }
@@ -91,3 +95,19 @@ fn empty_map_serde() {
     let after: Map<u8, u8, 8> = deserialize(&bytes).unwrap();
     assert!(after.is_empty());
 }
+
+#[test]
+fn rejects_too_many_keys() {
+    let before: Map<u8, u8, 9> = (0..9).map(|k| (k, k)).collect();
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    let after: Result<Map<u8, u8, 8>, _> = deserialize(&bytes);
+    assert!(after.is_err());
+}
+
+#[test]
+fn updates_duplicate_keys_on_deserialization() {
+    let bytes: Vec<u8> = serialize(&vec![(1_u8, 1_u8), (2, 2), (1, 42)]).unwrap();
+    let after: Map<u8, u8, 2> = deserialize(&bytes).unwrap();
+    assert_eq!(2, after.len());
+    assert_eq!(42, after[&1]);
+}
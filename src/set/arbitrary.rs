@@ -0,0 +1,52 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Map, Set};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, T: PartialEq + Arbitrary<'a>, const N: usize> Arbitrary<'a> for Set<T, N> {
+    /// Make a set of values taken from the input, until it ends or
+    /// a new value doesn't fit. Duplicates are collapsed.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut map: Map<T, (), N> = Map::new();
+        for k in u.arbitrary_iter::<T>()? {
+            if map.insert_checked(k?, ()).is_err() {
+                break;
+            }
+        }
+        Ok(Self { map })
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn makes_set_from_bytes() {
+        let bytes: Vec<u8> = [1, 3, 1, 1].repeat(8);
+        let mut u = Unstructured::new(&bytes);
+        let s = Set::<u8, 4>::arbitrary(&mut u).unwrap();
+        assert_eq!(2, s.len());
+        assert!(s.contains(&1));
+        assert!(s.contains(&3));
+    }
+}
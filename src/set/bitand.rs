@@ -0,0 +1,86 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Set;
+use core::ops::{BitAnd, BitAndAssign};
+
+impl<T: PartialEq + Clone, const N: usize, const M: usize> BitAnd<&Set<T, M>> for &Set<T, N> {
+    type Output = Set<T, N>;
+
+    /// The intersection of two sets, as a new set.
+    ///
+    /// ```
+    /// let a: micromap::Set<u8, 4> = [1, 2, 3].into_iter().collect();
+    /// let b: micromap::Set<u8, 4> = [2, 3, 4].into_iter().collect();
+    /// let c = &a & &b;
+    /// assert_eq!(2, c.len());
+    /// ```
+    #[inline]
+    fn bitand(self, rhs: &Set<T, M>) -> Set<T, N> {
+        self.iter()
+            .filter(|k| rhs.contains_key(*k))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> BitAndAssign<&Set<T, M>> for Set<T, N> {
+    /// Keep only the keys that are also in `rhs`.
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &Set<T, M>) {
+        self.retain(|k| rhs.contains_key(k));
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn intersects_overlapping_sets() {
+        let a: Set<u8, 4> = [1, 2, 3].into_iter().collect();
+        let b: Set<u8, 3> = [2, 3, 4].into_iter().collect();
+        let expected: Set<u8, 4> = [2, 3].into_iter().collect();
+        assert_eq!(expected, &a & &b);
+        let mut c = a;
+        c &= &b;
+        assert_eq!(expected, c);
+    }
+
+    #[test]
+    fn intersects_disjoint_sets() {
+        let a: Set<u8, 4> = [1, 2].into_iter().collect();
+        let b: Set<u8, 4> = [3, 4].into_iter().collect();
+        assert!((&a & &b).is_empty());
+        let mut c = a;
+        c &= &b;
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn intersects_with_itself() {
+        let a: Set<u8, 4> = [1, 2].into_iter().collect();
+        assert_eq!(a, &a & &a);
+        let mut c = a.clone();
+        c &= &a;
+        assert_eq!(a, c);
+    }
+}
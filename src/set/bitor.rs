@@ -0,0 +1,111 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Set;
+use core::ops::{BitOr, BitOrAssign};
+
+impl<T: PartialEq + Clone, const N: usize, const M: usize> BitOr<&Set<T, M>> for &Set<T, N> {
+    type Output = Set<T, N>;
+
+    /// The union of two sets, as a new set.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are more distinct keys than `N`.
+    ///
+    /// ```
+    /// let a: micromap::Set<u8, 4> = [1, 2].into_iter().collect();
+    /// let b: micromap::Set<u8, 4> = [2, 3].into_iter().collect();
+    /// let c = &a | &b;
+    /// assert_eq!(3, c.len());
+    /// ```
+    #[inline]
+    fn bitor(self, rhs: &Set<T, M>) -> Set<T, N> {
+        let mut s = self.clone();
+        s |= rhs;
+        s
+    }
+}
+
+impl<T: PartialEq + Clone, const N: usize, const M: usize> BitOrAssign<&Set<T, M>> for Set<T, N> {
+    /// Add all keys of `rhs`, which are not here yet.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are more distinct keys than `N`.
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &Set<T, M>) {
+        for k in rhs {
+            if !self.contains_key(k) {
+                self.insert(k.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn unites_overlapping_sets() {
+        let a: Set<u8, 4> = [1, 2, 3].into_iter().collect();
+        let b: Set<u8, 3> = [2, 3, 4].into_iter().collect();
+        let expected: Set<u8, 4> = [1, 2, 3, 4].into_iter().collect();
+        assert_eq!(expected, &a | &b);
+        let mut c = a;
+        c |= &b;
+        assert_eq!(expected, c);
+    }
+
+    #[test]
+    fn unites_disjoint_sets() {
+        let a: Set<u8, 4> = [1, 2].into_iter().collect();
+        let b: Set<u8, 4> = [3, 4].into_iter().collect();
+        let expected: Set<u8, 4> = [1, 2, 3, 4].into_iter().collect();
+        assert_eq!(expected, &a | &b);
+        let mut c = a;
+        c |= &b;
+        assert_eq!(expected, c);
+    }
+
+    #[test]
+    fn unites_with_itself() {
+        let a: Set<u8, 2> = [1, 2].into_iter().collect();
+        assert_eq!(a, &a | &a);
+        let mut c = a.clone();
+        c |= &a;
+        assert_eq!(a, c);
+    }
+
+    #[test]
+    #[cfg_attr(
+        feature = "std",
+        should_panic(expected = "No more keys available in the map")
+    )]
+    #[cfg_attr(not(feature = "std"), should_panic(expected = "index out of bounds"))]
+    #[cfg(debug_assertions)]
+    fn unites_beyond_capacity() {
+        let a: Set<u8, 2> = [1, 2].into_iter().collect();
+        let b: Set<u8, 2> = [3, 4].into_iter().collect();
+        let _c = &a | &b;
+    }
+}
@@ -27,3 +27,40 @@ impl<T: Clone + PartialEq, const N: usize> Clone for Set<T, N> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn drops_cloned_items_when_clone_panics() {
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+        #[derive(PartialEq)]
+        struct Item(u8, Rc<Cell<usize>>);
+        impl Clone for Item {
+            fn clone(&self) -> Self {
+                assert!(self.0 != 2, "Can't clone the third item");
+                Self(self.0, Rc::clone(&self.1))
+            }
+        }
+        impl Drop for Item {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+            }
+        }
+        let drops = Rc::new(Cell::new(0));
+        let mut s: Set<Item, 8> = Set::new();
+        for i in 0..4 {
+            s.insert(Item(i, Rc::clone(&drops)));
+        }
+        let r = catch_unwind(AssertUnwindSafe(|| s.clone()));
+        assert!(r.is_err());
+        assert_eq!(2, drops.get());
+        assert_eq!(4, s.len());
+        drop(s);
+        assert_eq!(6, drops.get());
+    }
+}
@@ -23,7 +23,6 @@ use crate::{Map, Set};
 impl<T: PartialEq, const N: usize> Default for Set<T, N> {
     /// Make a default empty [`Set`].
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self::new()
     }
@@ -42,4 +41,60 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
             map: Map::<T, (), N>::new(),
         }
     }
+
+    /// Make it from a slice, cloning its distinct items.
+    ///
+    /// When the slice has equal items, the first occurrence wins: it gets
+    /// cloned into the set, while all later ones are ignored. Items are
+    /// stored in the order of their first occurrence.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are more distinct items in the slice than `N`.
+    #[inline]
+    #[must_use]
+    pub fn dedup_from_slice(items: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let mut s: Self = Self::new();
+        for k in items {
+            if !s.contains_key(k) {
+                s.insert(k.clone());
+            }
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn makes_set_from_slice_with_duplicates() {
+        let items = ["one".to_string(), "two".to_string(), "one".to_string()];
+        let s: Set<String, 4> = Set::dedup_from_slice(&items);
+        assert_eq!(2, s.len());
+        assert!(s.contains_key("one"));
+        assert!(s.contains_key("two"));
+        assert_eq!(3, items.len());
+        assert_eq!("one", items[2]);
+    }
+
+    #[test]
+    fn keeps_first_occurrence_in_slice() {
+        #[derive(Clone, Debug)]
+        struct Item(u8, &'static str);
+        impl PartialEq for Item {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        let items = [Item(1, "first"), Item(2, "other"), Item(1, "second")];
+        let s: Set<Item, 2> = Set::dedup_from_slice(&items);
+        assert_eq!(2, s.len());
+        assert_eq!("first", s.get(&Item(1, "")).unwrap().1);
+    }
 }
@@ -27,12 +27,53 @@ impl<T: PartialEq + Debug, const N: usize> Debug for Set<T, N> {
     }
 }
 
+/// A [`Debug`] view of the [`Set`], which prints its elements sorted.
+struct SortedDebug<'a, T: PartialEq, const N: usize> {
+    set: &'a Set<T, N>,
+}
+
+impl<T: Ord + Debug, const N: usize> Debug for SortedDebug<'_, T, N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let indices = self.set.map.sorted_indices();
+        f.debug_set()
+            .entries(
+                indices[..self.set.len()]
+                    .iter()
+                    .map(|i| &self.set.map.item_ref(*i).0),
+            )
+            .finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> Set<T, N> {
+    /// Make a [`Debug`] view, which prints elements in sorted order.
+    ///
+    /// Equal sets print the same string, no matter in which order
+    /// their elements were inserted:
+    ///
+    /// ```
+    /// let mut s: micromap::Set<u8, 4> = micromap::Set::new();
+    /// s.insert(2);
+    /// s.insert(1);
+    /// assert_eq!("{1, 2}", format!("{:?}", s.debug_sorted()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn debug_sorted(&self) -> impl Debug + '_
+    where
+        T: Ord + Debug,
+    {
+        SortedDebug { set: self }
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
 
     #[test]
+    #[allow(clippy::uninlined_format_args)]
     fn debugs_set() {
         let mut m: Set<String, 10> = Set::new();
         m.insert("one".to_string());
@@ -41,6 +82,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::uninlined_format_args)]
     fn debug_alternate_set() {
         let mut m: Set<String, 10> = Set::new();
         m.insert("one".to_string());
@@ -53,4 +95,24 @@ mod test {
             format!("{:#?}", m)
         );
     }
+
+    #[test]
+    fn debugs_sorted_set() {
+        let mut s1: Set<String, 10> = Set::new();
+        s1.insert("two".to_string());
+        s1.insert("one".to_string());
+        s1.insert("three".to_string());
+        let mut s2: Set<String, 10> = Set::new();
+        s2.insert("three".to_string());
+        s2.insert("one".to_string());
+        s2.insert("two".to_string());
+        assert_eq!(
+            format!("{:?}", s1.debug_sorted()),
+            format!("{:?}", s2.debug_sorted())
+        );
+        assert_eq!(
+            r#"{"one", "three", "two"}"#,
+            format!("{:?}", s1.debug_sorted())
+        );
+    }
 }
@@ -22,6 +22,8 @@ use crate::Set;
 use core::fmt::{self, Display, Formatter, Write};
 
 impl<T: PartialEq + Display, const N: usize> Display for Set<T, N> {
+    /// Print the values as `{t1, t2}`, in the order they are stored
+    /// in the set. Only `core::fmt` is used, so it works without `std`.
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let mut first = true;
         f.write_char('{')?;
@@ -44,10 +46,20 @@ mod test {
     use super::*;
 
     #[test]
+    #[allow(clippy::uninlined_format_args)]
     fn displays_set() {
         let mut m: Set<String, 10> = Set::new();
         m.insert("one".to_string());
         m.insert("two".to_string());
         assert_eq!(r#"{"one", "two"}"#, format!("{:?}", m));
     }
+
+    #[test]
+    fn displays_set_without_quotes() {
+        let mut s: Set<&str, 10> = Set::new();
+        s.insert("one");
+        s.insert("two");
+        assert_eq!("{one, two}", format!("{s}"));
+        assert_eq!("{}", format!("{}", Set::<u8, 2>::new()));
+    }
 }
@@ -18,14 +18,29 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::SetDrain;
+use crate::{Set, SetDrain};
+use core::fmt::{self, Debug, Formatter};
 use core::iter::FusedIterator;
 
-impl<'a, K: PartialEq> Iterator for SetDrain<'a, K> {
+impl<T: PartialEq, const N: usize> Set<T, N> {
+    /// Clears the set, returning all elements transformed by the function.
+    ///
+    /// It is the same as `drain().map(f)`: if the returned iterator is dropped
+    /// before being fully consumed, it drops the remaining elements, without
+    /// calling the function on them.
+    #[inline]
+    pub fn drain_map<'a, W, F: FnMut(T) -> W + 'a>(
+        &'a mut self,
+        mut f: F,
+    ) -> impl Iterator<Item = W> + 'a {
+        self.map.drain_map(move |k, ()| f(k))
+    }
+}
+
+impl<K: PartialEq> Iterator for SetDrain<'_, K> {
     type Item = K;
 
     #[inline]
-    #[must_use]
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|(k, ())| k)
     }
@@ -36,11 +51,102 @@ impl<'a, K: PartialEq> Iterator for SetDrain<'a, K> {
     }
 }
 
-impl<'a, K: PartialEq> ExactSizeIterator for SetDrain<'a, K> {
+impl<K: PartialEq> DoubleEndedIterator for SetDrain<'_, K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(k, ())| k)
+    }
+}
+
+impl<K: PartialEq> ExactSizeIterator for SetDrain<'_, K> {
     #[inline]
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<'a, K: PartialEq> FusedIterator for SetDrain<'a, K> {}
+impl<K: PartialEq> FusedIterator for SetDrain<'_, K> {}
+
+impl<K: PartialEq + Debug> Debug for SetDrain<'_, K> {
+    /// Print the elements not drained yet.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.iter
+                    .iter
+                    .as_slice()
+                    .iter()
+                    .map(|p| &unsafe { p.assume_init_ref() }.0),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn drains_and_maps() {
+        let mut s: Set<u8, 10> = Set::new();
+        s.insert(1);
+        s.insert(2);
+        let total: u32 = s.drain_map(|k| u32::from(k) * 10).sum();
+        assert_eq!(30, total);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn drops_remaining_elements_of_drain_map() {
+        use std::rc::Rc;
+        let mut s: Set<Rc<u8>, 10> = Set::new();
+        let items: Vec<Rc<u8>> = (0..5).map(Rc::new).collect();
+        for i in &items {
+            s.insert(Rc::clone(i));
+        }
+        let first = s.drain_map(|k| k).next();
+        assert!(s.is_empty());
+        assert_eq!(2, Rc::strong_count(first.as_ref().unwrap()));
+        drop(first);
+        assert!(items.iter().all(|i| Rc::strong_count(i) == 1));
+    }
+
+    #[test]
+    fn drains_from_both_ends() {
+        let mut s: Set<u8, 10> = Set::new();
+        for k in 1..=5 {
+            s.insert(k);
+        }
+        let mut d = s.drain();
+        assert_eq!(5, d.len());
+        assert_eq!(Some(1), d.next());
+        assert_eq!((4, Some(4)), d.size_hint());
+        assert_eq!(Some(5), d.next_back());
+        assert_eq!(3, d.len());
+        assert_eq!("[2, 3, 4]", format!("{d:?}"));
+        assert_eq!(vec![4, 3, 2], d.rev().collect::<Vec<_>>());
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn drops_remaining_elements_of_partial_drain() {
+        use std::rc::Rc;
+        let mut s: Set<Rc<u8>, 10> = Set::new();
+        let items: Vec<Rc<u8>> = (0..5).map(Rc::new).collect();
+        for i in &items {
+            s.insert(Rc::clone(i));
+        }
+        let mut d = s.drain();
+        let first = d.next().unwrap();
+        let last = d.next_back().unwrap();
+        assert_eq!(3, d.len());
+        drop(d);
+        assert!(s.is_empty());
+        assert_eq!(2, Rc::strong_count(&first));
+        assert_eq!(2, Rc::strong_count(&last));
+        drop(first);
+        drop(last);
+        assert!(items.iter().all(|i| Rc::strong_count(i) == 1));
+    }
+}
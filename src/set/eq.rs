@@ -20,8 +20,8 @@
 
 use crate::Set;
 
-impl<T: PartialEq, const N: usize> PartialEq for Set<T, N> {
-    /// Two sets can be compared.
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<Set<T, M>> for Set<T, N> {
+    /// Two sets can be compared, even if their capacities are different.
     ///
     /// For example:
     ///
@@ -41,9 +41,23 @@ impl<T: PartialEq, const N: usize> PartialEq for Set<T, N> {
     /// assert_eq!(m1, m2);
     /// ```
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &Set<T, M>) -> bool {
         self.map.eq(&other.map)
     }
 }
 
 impl<T: Eq, const N: usize> Eq for Set<T, N> {}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn compares_sets_of_different_capacities() {
+        let s1: Set<u8, 3> = Set::from([1, 2, 3]);
+        let s2: Set<u8, 8> = [3, 2, 1].into_iter().collect();
+        assert_eq!(s1, s2);
+        assert_ne!(s1, Set::<u8, 8>::new());
+    }
+}
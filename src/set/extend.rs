@@ -0,0 +1,64 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Set;
+
+impl<T: PartialEq, const N: usize> Set<T, N> {
+    /// Insert all values, while there is space for new ones.
+    ///
+    /// New values that come when the set is full are ignored, and the number
+    /// of them is returned. Values that are already in the set are not counted.
+    /// It never panics.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// let mut s: micromap::Set<u8, 2> = micromap::Set::new();
+    /// assert_eq!(1, s.extend_truncating([1, 2, 3, 1]));
+    /// assert_eq!(2, s.len());
+    /// ```
+    #[inline]
+    pub fn extend_truncating<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        self.map
+            .extend_truncating(iter.into_iter().map(|k| (k, ())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn extends_truncating_with_duplicates() {
+        let mut s: Set<u8, 3> = Set::new();
+        s.insert(1);
+        let dropped = s.extend_truncating([1, 2, 2, 3, 4, 3, 5, 1, 4]);
+        assert_eq!(3, dropped);
+        assert_eq!(Set::from([1, 2, 3]), s);
+    }
+
+    #[test]
+    fn extends_truncating_within_capacity() {
+        let mut s: Set<u8, 4> = Set::new();
+        assert_eq!(0, s.extend_truncating([1, 2, 1, 2]));
+        assert_eq!(2, s.len());
+    }
+}
@@ -18,11 +18,10 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::Set;
+use crate::{CapacityError, Map, Set};
 
 impl<T: PartialEq, const N: usize> FromIterator<T> for Set<T, N> {
     #[inline]
-    #[must_use]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut s: Self = Self::new();
         for k in iter {
@@ -34,8 +33,41 @@ impl<T: PartialEq, const N: usize> FromIterator<T> for Set<T, N> {
 
 impl<T: PartialEq, const N: usize> From<[T; N]> for Set<T, N> {
     #[inline]
-    #[must_use]
     fn from(arr: [T; N]) -> Self {
         Self::from_iter(arr)
     }
 }
+
+impl<T: PartialEq, const N: usize> Set<T, N> {
+    /// Make a set from an iterator, without panicking when it's too long.
+    ///
+    /// Duplicate values are collapsed, as in [`Set::insert`].
+    ///
+    /// # Errors
+    ///
+    /// If a new value comes when the set is already full, the iteration stops
+    /// and the rejected value is returned as the key of the error, while
+    /// the values collected so far are dropped.
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, CapacityError<T, ()>> {
+        Map::try_from_iter(iter.into_iter().map(|k| (k, ()))).map(|map| Self { map })
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn tries_from_iter_with_duplicates() {
+        let s = Set::<u8, 2>::try_from_iter([1, 2, 1, 2, 2]).unwrap();
+        assert_eq!(Set::from([1, 2]), s);
+    }
+
+    #[test]
+    fn tries_from_iter_beyond_capacity() {
+        let e = Set::<u8, 2>::try_from_iter([1, 2, 3, 1]);
+        assert_eq!(Err(CapacityError { key: 3, value: () }), e);
+    }
+}
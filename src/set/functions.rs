@@ -89,6 +89,104 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
         self.map.insert(k, ()).is_none()
     }
 
+    /// Add a value to the set, returning the index of its slot in the
+    /// internal array and whether it was newly inserted.
+    ///
+    /// If the value is already in the set, it stays untouched.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are too many values in the set already.
+    #[inline]
+    pub fn insert_full(&mut self, k: T) -> (usize, bool) {
+        let len = self.map.len();
+        let (i, ()) = self.map.intern(k, || ());
+        (i, self.map.len() > len)
+    }
+
+    /// Add a value to the set, never panicking on overflow.
+    ///
+    /// Returns `Ok(true)` if the value was added, or `Ok(false)` if it
+    /// was in the set already.
+    ///
+    /// # Errors
+    ///
+    /// If the value is absent and the set is full, the value is returned back.
+    #[inline]
+    pub fn insert_checked(&mut self, k: T) -> Result<bool, T> {
+        if self.contains_key(&k) {
+            return Ok(false);
+        }
+        self.map
+            .insert_checked(k, ())
+            .map(|_| true)
+            .map_err(|p| p.0)
+    }
+
+    /// Remove the value if it's in the set, or insert it otherwise.
+    ///
+    /// Returns `true` if the value is in the set after the call.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if the value is absent and there are too many pairs in the set already.
+    #[inline]
+    pub fn toggle(&mut self, value: T) -> bool {
+        if self.remove(&value) {
+            false
+        } else {
+            self.insert(value);
+            true
+        }
+    }
+
+    /// Move the value to the first slot of the internal array,
+    /// shifting all values before it one slot further.
+    ///
+    /// Returns `true` if the value was found.
+    #[inline]
+    pub fn move_to_front<Q: PartialEq + ?Sized>(&mut self, k: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.move_key_to_front(k)
+    }
+
+    /// Remove the value from the last slot of the internal array and return it.
+    ///
+    /// Together with [`Set::move_to_front`] it evicts the least
+    /// recently used value.
+    #[inline]
+    pub fn evict_back(&mut self) -> Option<T> {
+        self.map.evict_back().map(|p| p.0)
+    }
+
+    /// Merge with another set into a new set of the given capacity.
+    ///
+    /// # Panics
+    ///
+    /// It may panic if there are more distinct values than `R`.
+    #[inline]
+    #[must_use]
+    pub fn concat<const M: usize, const R: usize>(self, other: Set<T, M>) -> Set<T, R> {
+        Set {
+            map: self.map.concat(other.map),
+        }
+    }
+
+    /// Print all values into a string, separated by the separator.
+    ///
+    /// Values go in the order they are stored in the set.
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn join(&self, sep: &str) -> String
+    where
+        T: core::fmt::Display,
+    {
+        self.map.keys_joined(sep)
+    }
+
     /// Get a reference to a single value.
     #[inline]
     #[must_use]
@@ -99,6 +197,37 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
         self.map.get_key_value(k).map(|p| p.0)
     }
 
+    /// Get mutable references to many values at once.
+    ///
+    /// It works exactly like [`crate::Map::get_disjoint_mut`], but returns
+    /// the values stored in the set. It's useful when `==` ignores some fields
+    /// of the values. Changing the values in a way that affects their equality
+    /// breaks the set.
+    ///
+    /// # Panics
+    ///
+    /// If any two keys point to the same value.
+    #[inline]
+    pub fn get_disjoint_mut<Q: PartialEq + ?Sized, const J: usize>(
+        &mut self,
+        ks: [&Q; J],
+    ) -> [Option<&mut T>; J]
+    where
+        T: Borrow<Q>,
+    {
+        self.map.disjoint_pairs_mut(ks).map(|p| p.map(|p| &mut p.0))
+    }
+
+    /// Get a reference to a single value, comparing values with the function.
+    ///
+    /// The function gets each stored value and the needle, and returns `true`
+    /// when they match, without changing `PartialEq` of `T`.
+    #[inline]
+    #[must_use]
+    pub fn get_by_cmp<Q: ?Sized, F: Fn(&T, &Q) -> bool>(&self, needle: &Q, eq: F) -> Option<&T> {
+        self.iter().find(|k| eq(k, needle))
+    }
+
     /// Remove all pairs from it, but keep the space intact for future use.
     #[inline]
     pub fn clear(&mut self) {
@@ -111,6 +240,13 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
         self.map.retain(|k, ()| f(k));
     }
 
+    /// Retains only the elements specified by the predicate, returning
+    /// the number of removed ones.
+    #[inline]
+    pub fn retain_count<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.map.retain_count(|k, ()| f(k))
+    }
+
     /// Removes a key from the set, returning the stored key and value if the
     /// key was previously in the set.
     #[inline]
@@ -121,3 +257,158 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
         self.map.remove_entry(k).map(|p| p.0)
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Tagged(u8, i32);
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl core::borrow::Borrow<u8> for Tagged {
+        fn borrow(&self) -> &u8 {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn toggles_value() {
+        let mut s: Set<&str, 4> = Set::new();
+        s.insert("other");
+        assert!(s.toggle("one"));
+        assert!(s.contains_key("one"));
+        assert!(!s.toggle("one"));
+        assert!(!s.contains_key("one"));
+        assert!(s.toggle("one"));
+        assert!(s.contains_key("one"));
+        assert_eq!(2, s.len());
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut s: Set<char, 3> = Set::new();
+        for k in ['a', 'b', 'c'] {
+            s.insert(k);
+            s.move_to_front(&k);
+        }
+        assert!(s.move_to_front(&'a'));
+        assert!(!s.move_to_front(&'z'));
+        assert_eq!(vec!['a', 'c', 'b'], s.iter().copied().collect::<Vec<_>>());
+        assert_eq!(Some('b'), s.evict_back());
+        assert_eq!(Some('c'), s.evict_back());
+        assert_eq!(Some('a'), s.evict_back());
+        assert_eq!(None, s.evict_back());
+    }
+
+    #[test]
+    fn insert_checked_in_all_cases() {
+        let mut s: Set<u8, 2> = Set::new();
+        assert_eq!(Ok(true), s.insert_checked(1));
+        assert_eq!(Ok(false), s.insert_checked(1));
+        assert_eq!(Ok(true), s.insert_checked(2));
+        assert_eq!(Err(3), s.insert_checked(3));
+        assert_eq!(Ok(false), s.insert_checked(2));
+        assert_eq!(2, s.len());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn joins_values() {
+        let s: Set<u8, 4> = Set::from([1, 2, 3, 4]);
+        let joined = s.join(" | ");
+        let mut parts: Vec<&str> = joined.split(" | ").collect();
+        parts.sort_unstable();
+        assert_eq!(vec!["1", "2", "3", "4"], parts);
+    }
+
+    #[test]
+    fn insert_full_returns_index_and_novelty() {
+        let mut s: Set<&str, 4> = Set::new();
+        for k in ["one", "two", "three"] {
+            let (i, added) = s.insert_full(k);
+            assert!(added);
+            assert_eq!(s.iter().position(|x| *x == k), Some(i));
+        }
+        s.remove("one");
+        let (i, added) = s.insert_full("two");
+        assert!(!added);
+        assert_eq!(s.iter().position(|x| *x == "two"), Some(i));
+        assert_eq!(2, s.len());
+    }
+
+    #[test]
+    fn concats_overlapping_sets() {
+        let a: Set<u8, 3> = Set::from([1, 2, 3]);
+        let b: Set<u8, 2> = Set::from([3, 4]);
+        let s: Set<u8, 4> = a.concat(b);
+        assert_eq!(Set::from([1, 2, 3, 4]), s);
+    }
+
+    #[test]
+    fn concats_disjoint_sets() {
+        let a: Set<u8, 2> = Set::from([1, 2]);
+        let b: Set<u8, 3> = Set::from([3, 4, 5]);
+        let s: Set<u8, 5> = a.concat(b);
+        assert_eq!(Set::from([1, 2, 3, 4, 5]), s);
+    }
+
+    #[test]
+    #[cfg_attr(
+        feature = "std",
+        should_panic(expected = "No more keys available in the map")
+    )]
+    #[cfg_attr(not(feature = "std"), should_panic(expected = "index out of bounds"))]
+    #[cfg(debug_assertions)]
+    fn concats_beyond_capacity() {
+        let a: Set<u8, 2> = Set::from([1, 2]);
+        let b: Set<u8, 2> = Set::from([3, 4]);
+        let _s: Set<u8, 3> = a.concat(b);
+    }
+
+    #[test]
+    fn get_disjoint_mut_present_and_absent() {
+        let mut s: Set<Tagged, 4> = Set::new();
+        s.insert(Tagged(1, 10));
+        s.insert(Tagged(2, 20));
+        let [a, b, c] = s.get_disjoint_mut([&2, &3, &1]);
+        a.unwrap().1 += 2;
+        assert!(b.is_none());
+        c.unwrap().1 += 1;
+        assert_eq!(22, s.get(&2).unwrap().1);
+        assert_eq!(11, s.get(&1).unwrap().1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Overlapping keys")]
+    fn get_disjoint_mut_with_overlapping_keys() {
+        let mut s: Set<Tagged, 4> = Set::new();
+        s.insert(Tagged(1, 10));
+        s.insert(Tagged(2, 20));
+        let _ = s.get_disjoint_mut([&1, &2, &1]);
+    }
+
+    #[test]
+    fn retains_and_counts_removed() {
+        let mut s: Set<u8, 8> = Set::from([1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(4, s.retain_count(|v| v % 2 == 0));
+        assert_eq!(vec![2, 4, 6, 8], s.iter().copied().collect::<Vec<_>>());
+        assert_eq!(0, s.retain_count(|_| true));
+    }
+
+    #[test]
+    fn gets_by_custom_comparison() {
+        let s: Set<&str, 3> = Set::from([" one", "two ", " three "]);
+        let trimmed = |v: &&str, n: &str| v.trim() == n.trim();
+        assert_eq!(Some(&"two "), s.get_by_cmp("two", trimmed));
+        assert_eq!(Some(&" three "), s.get_by_cmp("  three", trimmed));
+        assert_eq!(None, s.get_by_cmp("four", trimmed));
+        assert_eq!(None, s.get("two"));
+    }
+}
@@ -25,7 +25,7 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     /// Make an iterator over all pairs.
     #[inline]
     #[must_use]
-    pub fn iter(&self) -> SetIter<T> {
+    pub fn iter(&self) -> SetIter<'_, T> {
         SetIter {
             iter: self.map.keys(),
         }
@@ -36,7 +36,6 @@ impl<'a, T> Iterator for SetIter<'a, T> {
     type Item = &'a T;
 
     #[inline]
-    #[must_use]
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next()
     }
@@ -51,7 +50,6 @@ impl<T: PartialEq, const N: usize> Iterator for SetIntoIter<T, N> {
     type Item = T;
 
     #[inline]
-    #[must_use]
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next()
     }
@@ -67,7 +65,6 @@ impl<'a, T: PartialEq, const N: usize> IntoIterator for &'a Set<T, N> {
     type IntoIter = SetIter<'a, T>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -78,7 +75,6 @@ impl<T: PartialEq, const N: usize> IntoIterator for Set<T, N> {
     type IntoIter = SetIntoIter<T, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         SetIntoIter {
             iter: self.map.into_keys(),
@@ -86,7 +82,7 @@ impl<T: PartialEq, const N: usize> IntoIterator for Set<T, N> {
     }
 }
 
-impl<'a, T> ExactSizeIterator for SetIter<'a, T> {
+impl<T> ExactSizeIterator for SetIter<'_, T> {
     fn len(&self) -> usize {
         self.iter.len()
     }
@@ -98,6 +94,6 @@ impl<T: PartialEq, const N: usize> ExactSizeIterator for SetIntoIter<T, N> {
     }
 }
 
-impl<'a, T> FusedIterator for SetIter<'a, T> {}
+impl<T> FusedIterator for SetIter<'_, T> {}
 
 impl<T: PartialEq, const N: usize> FusedIterator for SetIntoIter<T, N> {}
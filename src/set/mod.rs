@@ -18,17 +18,28 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod bitand;
+mod bitor;
+mod bitxor;
 mod clone;
 mod ctors;
 mod debug;
 mod display;
 mod drain;
 mod eq;
+mod extend;
+#[cfg(feature = "defmt")]
+mod format;
 mod from;
 mod functions;
 mod iterators;
+mod ord;
 #[cfg(feature = "serde")]
 mod serialization;
+mod sorted;
+mod sub;
 
 use crate::Map;
 
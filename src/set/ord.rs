@@ -0,0 +1,56 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Set;
+use core::cmp::Ordering;
+
+impl<T: Ord, const N: usize> PartialOrd for Set<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for Set<T, N> {
+    /// Two sets can be ordered.
+    ///
+    /// They are compared as sorted sequences of their elements, that's why
+    /// the result doesn't depend on the order of insertion. Since the set
+    /// is not sorted, it takes O(n log n).
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.map.cmp(&other.map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn compares_sets_built_in_different_order() {
+        let s1: Set<u8, 4> = Set::from_iter([1, 2, 3]);
+        let s2: Set<u8, 4> = Set::from_iter([3, 1, 2]);
+        assert_eq!(Ordering::Equal, s1.cmp(&s2));
+        assert!(Set::<u8, 4>::from_iter([1, 3]) > s1);
+        assert!(Set::<u8, 4>::from_iter([1, 2]) < s1);
+    }
+}
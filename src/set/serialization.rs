@@ -21,7 +21,7 @@
 use crate::Set;
 use core::fmt::Formatter;
 use core::marker::PhantomData;
-use serde::de::{SeqAccess, Visitor};
+use serde::de::{Error, SeqAccess, Visitor};
 use serde::ser::SerializeSeq;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -31,7 +31,7 @@ impl<T: PartialEq + Serialize, const N: usize> Serialize for Set<T, N> {
         S: Serializer,
     {
         let mut seq = serializer.serialize_seq(Some(self.len()))?;
-        for k in self.iter() {
+        for k in self {
             seq.serialize_element(k)?;
         }
         seq.end()
@@ -53,7 +53,11 @@ impl<'de, T: PartialEq + Deserialize<'de>, const N: usize> Visitor<'de> for Vi<T
     {
         let mut m: Self::Value = Set::new();
         while let Some(key) = seq.next_element()? {
-            m.insert(key);
+            if m.insert_checked(key).is_err() {
+                return Err(A::Error::custom(format_args!(
+                    "more than {N} distinct values in a Set"
+                )));
+            }
         }
         Ok(m)
     }
@@ -87,3 +91,18 @@ fn empty_set_serde() {
     let after: Set<u8, 8> = deserialize(&bytes).unwrap();
     assert!(after.is_empty());
 }
+
+#[test]
+fn rejects_too_many_values() {
+    let before: Set<u8, 5> = Set::from([1, 2, 3, 4, 5]);
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    let after: Result<Set<u8, 4>, _> = deserialize(&bytes);
+    assert!(after.is_err());
+}
+
+#[test]
+fn collapses_duplicates_on_deserialization() {
+    let bytes: Vec<u8> = serialize(&vec![1_u8, 2, 1, 2, 2]).unwrap();
+    let after: Set<u8, 2> = deserialize(&bytes).unwrap();
+    assert_eq!(Set::from([1, 2]), after);
+}
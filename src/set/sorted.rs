@@ -0,0 +1,127 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Set;
+use core::borrow::Borrow;
+use core::ops::RangeBounds;
+
+impl<T: PartialEq + Ord, const N: usize> Set<T, N> {
+    /// Sort the values, in ascending order.
+    ///
+    /// Since all values in the set are distinct, the sorting is
+    /// the same as the one of [`crate::Map::sort_unstable_keys`].
+    /// The order stays until the set is modified again.
+    #[inline]
+    pub fn sort(&mut self) {
+        self.map.sort_unstable_keys();
+    }
+
+    /// Sort the values, in ascending order.
+    ///
+    /// It is the same as [`Set::sort`], which is not slower either,
+    /// since there are no equal values in the set.
+    #[inline]
+    pub fn sort_unstable(&mut self) {
+        self.map.sort_unstable_keys();
+    }
+
+    /// Iterate over the values in the range, in ascending order.
+    ///
+    /// The values must be sorted, with [`Set::sort`], otherwise the result
+    /// is not specified. In the "debug" mode it panics if they are not sorted.
+    ///
+    /// ```
+    /// let mut s: micromap::Set<u8, 4> = [3, 1, 2].into_iter().collect();
+    /// s.sort();
+    /// let found: Vec<&u8> = s.range(..3).collect();
+    /// assert_eq!(vec![&1, &2], found);
+    /// ```
+    #[inline]
+    pub fn range<Q: Ord + ?Sized, R: RangeBounds<Q>>(&self, range: R) -> impl Iterator<Item = &T>
+    where
+        T: Borrow<Q>,
+    {
+        self.map.range(range).map(|p| p.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn sorted_set() -> Set<u8, 10> {
+        let mut s: Set<u8, 10> = Set::from([7, 2, 9, 0, 4, 1, 8, 3, 6, 5]);
+        s.sort();
+        s
+    }
+
+    #[test]
+    fn sorts_values() {
+        let mut s: Set<i32, 8> = Set::from([5, -3, 8, 1, 0, 9, 2, 4]);
+        s.sort();
+        assert_eq!(
+            vec![-3, 0, 1, 2, 4, 5, 8, 9],
+            s.iter().copied().collect::<Vec<_>>()
+        );
+        for v in [5, -3, 8, 1, 0, 9, 2, 4] {
+            assert!(s.contains_key(&v));
+        }
+        assert!(!s.contains_key(&3));
+    }
+
+    #[test]
+    fn sorts_values_unstable() {
+        let mut s: Set<&str, 4> = Set::from(["delta", "alpha", "charlie", "bravo"]);
+        s.remove("charlie");
+        s.sort_unstable();
+        assert_eq!(
+            vec!["alpha", "bravo", "delta"],
+            s.iter().copied().collect::<Vec<_>>()
+        );
+        for v in ["alpha", "bravo", "delta"] {
+            assert!(s.contains_key(v));
+        }
+        assert_eq!(3, s.len());
+    }
+
+    #[test]
+    fn iterates_inclusive_range() {
+        let s = sorted_set();
+        assert_eq!(vec![&3, &4, &5], s.range(3..=5).collect::<Vec<_>>());
+        assert_eq!(vec![&0, &1], s.range(..=1).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iterates_exclusive_range() {
+        let s = sorted_set();
+        assert_eq!(vec![&3, &4], s.range(3..5).collect::<Vec<_>>());
+        assert_eq!(vec![&0], s.range(..1).collect::<Vec<_>>());
+        assert_eq!(0, s.range(4..4).count());
+    }
+
+    #[test]
+    #[should_panic(expected = "The keys are not sorted")]
+    #[cfg(debug_assertions)]
+    fn iterates_range_of_unsorted_set() {
+        let s: Set<u8, 4> = Set::from([3, 1, 2, 0]);
+        let _ = s.range(1..).count();
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Map;
+use core::borrow::Borrow;
+use core::ops::{Bound, RangeBounds};
+
+impl<K: PartialEq + Ord, V, const N: usize> Map<K, V, N> {
+    /// Sort the pairs by their keys, in ascending order.
+    ///
+    /// After it, [`Map::get_sorted`] may be used for lookups in O(log n),
+    /// until the map is modified again: any insertion or removal may
+    /// break the order.
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, &str, 4> = micromap::Map::new();
+    /// m.insert(2, "two");
+    /// m.insert(1, "one");
+    /// m.sort_unstable_keys();
+    /// assert_eq!(Some(&"two"), m.get_sorted(&2));
+    /// ```
+    #[inline]
+    pub fn sort_unstable_keys(&mut self) {
+        let indices = self.sorted_indices();
+        for j in 0..self.len {
+            let mut i = indices[j];
+            while i < j {
+                i = indices[i];
+            }
+            self.pairs.swap(i, j);
+            #[cfg(feature = "insertion_order")]
+            self.order.swap(i, j);
+        }
+    }
+
+    /// Get a reference to a single value, using binary search.
+    ///
+    /// The pairs must be sorted by keys, with [`Map::sort_unstable_keys`],
+    /// otherwise the result is not specified. In the "debug" mode it panics
+    /// if they are not sorted.
+    #[inline]
+    #[must_use]
+    pub fn get_sorted<Q: Ord + ?Sized>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        debug_assert!(self.keys_sorted(), "The keys are not sorted");
+        let slice = self.as_slice();
+        slice
+            .binary_search_by(|p| p.0.borrow().cmp(k))
+            .ok()
+            .map(|i| &slice[i].1)
+    }
+
+    /// Does the map contain this key? Uses binary search.
+    ///
+    /// It takes O(log n), while [`Map::contains_key`] takes O(n), but
+    /// the pairs must be sorted by keys, with [`Map::sort_unstable_keys`],
+    /// otherwise the result is not specified. In the "debug" mode it panics
+    /// if they are not sorted.
+    #[inline]
+    #[must_use]
+    pub fn contains_key_sorted<Q: Ord + ?Sized>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get_sorted(k).is_some()
+    }
+
+    /// Iterate over the pairs with keys in the range, in ascending order.
+    ///
+    /// The pairs must be sorted by keys, with [`Map::sort_unstable_keys`],
+    /// otherwise the result is not specified. In the "debug" mode it panics
+    /// if they are not sorted. The bounds are found with binary search.
+    ///
+    /// ```
+    /// let mut m: micromap::Map<u8, &str, 4> = micromap::Map::new();
+    /// m.insert(3, "three");
+    /// m.insert(1, "one");
+    /// m.insert(2, "two");
+    /// m.sort_unstable_keys();
+    /// let keys: Vec<u8> = m.range(2..).map(|(k, _)| *k).collect();
+    /// assert_eq!(vec![2, 3], keys);
+    /// ```
+    #[inline]
+    pub fn range<Q: Ord + ?Sized, R: RangeBounds<Q>>(
+        &self,
+        range: R,
+    ) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        debug_assert!(self.keys_sorted(), "The keys are not sorted");
+        let slice = self.as_slice();
+        let start = match range.start_bound() {
+            Bound::Included(b) => slice.partition_point(|p| p.0.borrow() < b),
+            Bound::Excluded(b) => slice.partition_point(|p| p.0.borrow() <= b),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(b) => slice.partition_point(|p| p.0.borrow() <= b),
+            Bound::Excluded(b) => slice.partition_point(|p| p.0.borrow() < b),
+            Bound::Unbounded => slice.len(),
+        };
+        slice[start..end.max(start)].iter().map(|p| (&p.0, &p.1))
+    }
+
+    /// Internal function to check whether the pairs are sorted by their keys.
+    #[inline]
+    fn keys_sorted(&self) -> bool {
+        self.as_slice().windows(2).all(|w| w[0].0 <= w[1].0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn sorts_keys() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in [5, 3, 8, 1, 9, 2] {
+            m.insert(k, i32::from(k) * 10);
+        }
+        m.sort_unstable_keys();
+        assert!(m.keys_sorted());
+        assert_eq!(
+            vec![1, 2, 3, 5, 8, 9],
+            m.keys().copied().collect::<Vec<_>>()
+        );
+        for (k, v) in &m {
+            assert_eq!(i32::from(*k) * 10, *v);
+        }
+    }
+
+    #[test]
+    fn gets_sorted() {
+        let mut m: Map<String, usize, 8> = Map::new();
+        for k in ["delta", "alpha", "charlie", "bravo"] {
+            m.insert(k.to_string(), k.len());
+        }
+        m.sort_unstable_keys();
+        assert_eq!(Some(&5), m.get_sorted("delta"));
+        assert_eq!(Some(&7), m.get_sorted("charlie"));
+        assert_eq!(None, m.get_sorted("echo"));
+    }
+
+    #[test]
+    fn contains_key_sorted() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in [10, 4, 7, 1, 13] {
+            m.insert(k, 0);
+        }
+        m.sort_unstable_keys();
+        for k in 0..16 {
+            assert_eq!(m.contains_key(&k), m.contains_key_sorted(&k));
+        }
+        assert!(m.contains_key_sorted(&7));
+        assert!(!m.contains_key_sorted(&8));
+    }
+
+    #[test]
+    fn gets_sorted_from_empty_map() {
+        let m: Map<u8, i32, 8> = Map::new();
+        assert_eq!(None, m.get_sorted(&1));
+    }
+
+    #[test]
+    #[should_panic(expected = "The keys are not sorted")]
+    #[cfg(debug_assertions)]
+    fn gets_from_unsorted_map() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        m.insert(2, 2);
+        m.insert(1, 1);
+        let _ = m.get_sorted(&1);
+    }
+
+    fn sorted_map() -> Map<u8, i32, 10> {
+        let mut m: Map<u8, i32, 10> = Map::new();
+        for k in [7, 2, 9, 0, 4, 1, 8, 3, 6, 5] {
+            m.insert(k, i32::from(k) * 10);
+        }
+        m.sort_unstable_keys();
+        m
+    }
+
+    #[test]
+    fn iterates_inclusive_range() {
+        let m = sorted_map();
+        let found: Vec<(u8, i32)> = m.range(3..=5).map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(vec![(3, 30), (4, 40), (5, 50)], found);
+        assert_eq!(vec![&0, &1], m.range(..=1).map(|p| p.0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iterates_exclusive_range() {
+        let m = sorted_map();
+        assert_eq!(vec![&3, &4], m.range(3..5).map(|p| p.0).collect::<Vec<_>>());
+        assert_eq!(vec![&8, &9], m.range(8..).map(|p| p.0).collect::<Vec<_>>());
+        let after: Vec<&u8> = m
+            .range((core::ops::Bound::Excluded(7), core::ops::Bound::Unbounded))
+            .map(|p| p.0)
+            .collect();
+        assert_eq!(vec![&8, &9], after);
+        assert_eq!(10, m.range(..).count());
+    }
+
+    #[test]
+    fn iterates_empty_range() {
+        let m = sorted_map();
+        assert_eq!(0, m.range(5..5).count());
+        assert_eq!(0, m.range(20..).count());
+        let (lo, hi) = (5, 2);
+        assert_eq!(0, m.range(lo..hi).count());
+    }
+
+    #[test]
+    #[cfg(feature = "insertion_order")]
+    fn sorting_keeps_insertion_order() {
+        let mut m: Map<u8, i32, 8> = Map::new();
+        for k in [5, 3, 8, 1, 9, 2] {
+            m.insert(k, 0);
+        }
+        m.sort_unstable_keys();
+        assert_eq!(
+            vec![5, 3, 8, 1, 9, 2],
+            m.iter_insertion_order()
+                .map(|(k, _)| *k)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Helpers for property-testing the usage of [`Map`] against
+//! a shadow [`HashMap`].
+//!
+//! For example:
+//!
+//! ```
+//! use micromap::testing::{Op, ShadowChecker};
+//! let mut checker: ShadowChecker<u8, i32, 4> = ShadowChecker::new();
+//! checker.apply(&Op::Insert(1, 42));
+//! checker.apply(&Op::Get(1));
+//! checker.apply(&Op::Remove(1));
+//! checker.check();
+//! ```
+
+use crate::Map;
+use core::fmt::Debug;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// A single operation on a map.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Op<K, V> {
+    /// Insert a pair, returning the previous value.
+    Insert(K, V),
+    /// Remove a key, returning its value.
+    Remove(K),
+    /// Get a clone of the value of a key.
+    Get(K),
+    /// Remove all pairs, returning nothing.
+    Clear,
+}
+
+/// Apply the operation to the map, returning what it returned.
+///
+/// # Panics
+///
+/// It may panic if [`Op::Insert`] adds a key to a full map,
+/// exactly like [`Map::insert`] does.
+pub fn apply_op<K: PartialEq, V: Clone, const N: usize>(
+    map: &mut Map<K, V, N>,
+    op: Op<K, V>,
+) -> Option<V> {
+    match op {
+        Op::Insert(k, v) => map.insert(k, v),
+        Op::Remove(k) => map.remove(&k),
+        Op::Get(k) => map.get(&k).cloned(),
+        Op::Clear => {
+            map.clear();
+            None
+        }
+    }
+}
+
+/// Assert that two maps have the same pairs, in any order.
+///
+/// Capacities of the maps may be different. On mismatch, the message
+/// lists the pairs found only in one of the maps:
+///
+/// ```
+/// let a: micromap::Map<u8, i32, 4> = [(1, 1), (2, 2)].into_iter().collect();
+/// let b: micromap::Map<u8, i32, 8> = [(2, 2), (1, 1)].into_iter().collect();
+/// micromap::testing::assert_maps_eq_unordered(&a, &b);
+/// ```
+///
+/// # Panics
+///
+/// If the maps are different.
+pub fn assert_maps_eq_unordered<
+    K: PartialEq + Debug,
+    V: PartialEq + Debug,
+    const N: usize,
+    const M: usize,
+>(
+    left: &Map<K, V, N>,
+    right: &Map<K, V, M>,
+) {
+    let only_left: Vec<(&K, &V)> = left
+        .iter()
+        .filter(|(k, v)| right.get_key_value(*k) != Some((*k, *v)))
+        .collect();
+    let only_right: Vec<(&K, &V)> = right
+        .iter()
+        .filter(|(k, v)| left.get_key_value(*k) != Some((*k, *v)))
+        .collect();
+    assert!(
+        only_left.is_empty() && only_right.is_empty(),
+        "Maps are different\n  only in left: {only_left:?}\n  only in right: {only_right:?}"
+    );
+}
+
+/// A [`Map`] together with a [`HashMap`], which receive the same operations.
+///
+/// Every operation is applied to both, and their results are compared.
+/// Insertions of new keys into a full map are skipped for both.
+pub struct ShadowChecker<K: PartialEq, V, const N: usize> {
+    map: Map<K, V, N>,
+    shadow: HashMap<K, V>,
+}
+
+impl<K: Eq + Hash + Clone + Debug, V: PartialEq + Clone + Debug, const N: usize>
+    ShadowChecker<K, V, N>
+{
+    /// Make it, with both maps empty.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            map: Map::new(),
+            shadow: HashMap::new(),
+        }
+    }
+
+    /// The map being checked.
+    #[inline]
+    #[must_use]
+    pub const fn map(&self) -> &Map<K, V, N> {
+        &self.map
+    }
+
+    /// Apply the operation to both maps.
+    ///
+    /// # Panics
+    ///
+    /// If the results of the operation are different.
+    pub fn apply(&mut self, op: &Op<K, V>) {
+        if let Op::Insert(k, _) = op {
+            if self.map.len() == N && !self.map.contains_key(k) {
+                return;
+            }
+        }
+        let expected = match op {
+            Op::Insert(k, v) => self.shadow.insert(k.clone(), v.clone()),
+            Op::Remove(k) => self.shadow.remove(k),
+            Op::Get(k) => self.shadow.get(k).cloned(),
+            Op::Clear => {
+                self.shadow.clear();
+                None
+            }
+        };
+        let actual = apply_op(&mut self.map, op.clone());
+        assert_eq!(expected, actual, "Different results of {op:?}");
+    }
+
+    /// Check that both maps have the same pairs.
+    ///
+    /// # Panics
+    ///
+    /// If the maps are different.
+    pub fn check(&self) {
+        assert_eq!(self.shadow.len(), self.map.len(), "Different lengths");
+        for (k, v) in &self.map {
+            assert_eq!(self.shadow.get(k), Some(v), "Different values of {k:?}");
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone + Debug, V: PartialEq + Clone + Debug, const N: usize> Default
+    for ShadowChecker<K, V, N>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn applies_ops_in_parity_with_hash_map() {
+        let mut checker: ShadowChecker<u8, i32, 4> = ShadowChecker::new();
+        for i in 0..100_u8 {
+            let k = i % 7;
+            let op = match i % 5 {
+                0 | 1 => Op::Insert(k, i32::from(i)),
+                2 => Op::Remove(k / 2),
+                3 => Op::Get(k),
+                _ if i % 25 == 4 => Op::Clear,
+                _ => Op::Get(k + 1),
+            };
+            checker.apply(&op);
+            checker.check();
+        }
+        assert!(checker.map().len() <= 4);
+    }
+
+    #[test]
+    fn applies_op_to_map() {
+        let mut m: Map<u8, i32, 4> = Map::new();
+        assert_eq!(None, apply_op(&mut m, Op::Insert(1, 42)));
+        assert_eq!(Some(42), apply_op(&mut m, Op::Get(1)));
+        assert_eq!(Some(42), apply_op(&mut m, Op::Insert(1, 16)));
+        assert_eq!(Some(16), apply_op(&mut m, Op::Remove(1)));
+        assert_eq!(None, apply_op(&mut m, Op::Clear));
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn asserts_equal_maps() {
+        let a: Map<u8, i32, 4> = [(1, 1), (2, 2), (3, 3)].into_iter().collect();
+        let b: Map<u8, i32, 3> = [(3, 3), (1, 1), (2, 2)].into_iter().collect();
+        assert_maps_eq_unordered(&a, &b);
+        assert_maps_eq_unordered(&Map::<u8, i32, 1>::new(), &Map::<u8, i32, 2>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "only in left: [(2, 2)]\n  only in right: [(2, 20), (4, 4)]")]
+    fn asserts_different_maps() {
+        let a: Map<u8, i32, 4> = [(1, 1), (2, 2)].into_iter().collect();
+        let b: Map<u8, i32, 4> = [(1, 1), (2, 20), (4, 4)].into_iter().collect();
+        assert_maps_eq_unordered(&a, &b);
+    }
+}
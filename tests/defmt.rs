@@ -0,0 +1,87 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Renders maps and sets through defmt on the host, with a global logger
+// that captures the raw frames, and checks that every entry is encoded.
+
+#![cfg(feature = "defmt")]
+
+use micromap::{Map, Set};
+use std::sync::Mutex;
+
+static FRAMES: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+static SERIAL: Mutex<()> = Mutex::new(());
+
+#[defmt::global_logger]
+struct Capture;
+
+unsafe impl defmt::Logger for Capture {
+    fn acquire() {}
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {}
+
+    unsafe fn write(bytes: &[u8]) {
+        FRAMES.lock().unwrap().extend_from_slice(bytes);
+    }
+}
+
+defmt::timestamp!("{=u8}", 0);
+
+#[defmt::panic_handler]
+fn panic() -> ! {
+    core::panic!("defmt panic")
+}
+
+fn render(value: &impl defmt::Format) -> Vec<u8> {
+    FRAMES.lock().unwrap().clear();
+    defmt::println!("{}", value);
+    FRAMES.lock().unwrap().clone()
+}
+
+fn encodes(frames: &[u8], x: u32) -> bool {
+    frames.windows(4).any(|w| w == x.to_le_bytes())
+}
+
+#[test]
+fn formats_every_pair_of_map() {
+    let _serial = SERIAL.lock().unwrap();
+    let mut m: Map<u32, u32, 4> = Map::new();
+    m.insert(0x1A2B_3C4D, 0x5E6F_7081);
+    m.insert(0x92A3_B4C5, 0xD6E7_F809);
+    let frames = render(&m);
+    for (k, v) in &m {
+        assert!(encodes(&frames, *k), "key {k:#x} is missing");
+        assert!(encodes(&frames, *v), "value {v:#x} is missing");
+    }
+}
+
+#[test]
+fn formats_every_item_of_set() {
+    let _serial = SERIAL.lock().unwrap();
+    let s: Set<u32, 4> = [0x1A2B_3C4D, 0x5E6F_7081, 0x92A3_B4C5]
+        .into_iter()
+        .collect();
+    let frames = render(&s);
+    for x in &s {
+        assert!(encodes(&frames, *x), "item {x:#x} is missing");
+    }
+}
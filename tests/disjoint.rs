@@ -0,0 +1,75 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Cross-checks get_disjoint_mut() against sequential get_mut() calls,
+// for random maps of different capacities with String keys,
+// which are queried by &str.
+
+mod common;
+
+use common::Random;
+use micromap::Map;
+
+fn check<const N: usize, const J: usize>(rnd: &mut Random) {
+    let mut m: Map<String, usize, N> = Map::new();
+    for _ in 0..rnd.next(N + 1) {
+        let k = rnd.next(2 * N);
+        m.insert(format!("k{k}"), k);
+    }
+    if rnd.next(2) == 0 && !m.is_empty() {
+        let k = m.keys().nth(rnd.next(m.len())).unwrap().clone();
+        m.remove(&k);
+    }
+    let mut pool: Vec<String> = (0..2 * N + J).map(|k| format!("k{k}")).collect();
+    let mut queries: Vec<String> = vec![];
+    for _ in 0..J {
+        queries.push(pool.swap_remove(rnd.next(pool.len())));
+    }
+    let ks: [&str; J] = core::array::from_fn(|j| queries[j].as_str());
+    let expected: Vec<Option<usize>> = ks.iter().map(|k| m.get_mut(*k).map(|v| *v)).collect();
+    let found = m.get_disjoint_mut(ks);
+    assert_eq!(
+        expected,
+        found
+            .iter()
+            .map(|v| v.as_deref().copied())
+            .collect::<Vec<_>>(),
+        "for {ks:?} in {m:?}"
+    );
+    for v in found.into_iter().flatten() {
+        *v += 1000;
+    }
+    for (k, e) in ks.iter().zip(expected) {
+        assert_eq!(e.map(|v| v + 1000), m.get_mut(*k).map(|v| *v));
+    }
+}
+
+#[test]
+fn disjoint_matches_sequential_lookups() {
+    let mut rnd = Random(0x2545_F491_4F6C_DD1D);
+    for _ in 0..1000 {
+        check::<1, 1>(&mut rnd);
+        check::<1, 3>(&mut rnd);
+        check::<4, 2>(&mut rnd);
+        check::<8, 4>(&mut rnd);
+        check::<16, 5>(&mut rnd);
+        check::<32, 8>(&mut rnd);
+    }
+}
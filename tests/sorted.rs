@@ -0,0 +1,56 @@
+// Copyright (c) 2023-2025 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Cross-checks get_sorted() against get(), for random maps
+// of different capacities, after sort_unstable_keys().
+
+mod common;
+
+use common::Random;
+use micromap::Map;
+
+fn check<const N: usize>(rnd: &mut Random) {
+    let mut m: Map<usize, usize, N> = Map::new();
+    for _ in 0..rnd.next(N + 1) {
+        let k = rnd.next(2 * N);
+        m.insert(k, rnd.next(1000));
+    }
+    if rnd.next(2) == 0 && !m.is_empty() {
+        let k = *m.keys().nth(rnd.next(m.len())).unwrap();
+        m.remove(&k);
+    }
+    let before = m.clone();
+    m.sort_unstable_keys();
+    assert_eq!(before, m);
+    for k in 0..2 * N {
+        assert_eq!(m.get(&k), m.get_sorted(&k));
+    }
+}
+
+#[test]
+fn get_sorted_matches_get() {
+    let mut rnd = Random(0x2545_F491_4F6C_DD1D);
+    for _ in 0..500 {
+        check::<1>(&mut rnd);
+        check::<4>(&mut rnd);
+        check::<16>(&mut rnd);
+        check::<40>(&mut rnd);
+    }
+}